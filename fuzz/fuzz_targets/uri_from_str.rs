@@ -0,0 +1,30 @@
+/********************************************************************************
+ * Copyright (c) 2024 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+#![no_main]
+
+use std::str::FromStr;
+
+use libfuzzer_sys::fuzz_target;
+use up_rust::UUri;
+
+fuzz_target!(|data: &[u8]| {
+    // arbitrary bytes are converted lossily so that non-UTF-8 input
+    // also ends up being fed into the parser
+    let input = String::from_utf8_lossy(data);
+    if let Ok(uri) = UUri::from_str(&input) {
+        // any successfully parsed URI must survive a round trip
+        let serialized_uri = uri.to_uri(false);
+        assert_eq!(UUri::from_str(&serialized_uri).ok(), Some(uri));
+    }
+});
@@ -37,15 +37,19 @@ For user convenience, all of these modules export their types on up_rust top-lev
 
 ## Features
 
+* `ahash` provides the [`UUriMap`] type, a `HashMap` keyed by [`UUri`]s that uses [ahash](https://docs.rs/ahash) for faster hashing.
 * `communication` enables support for the [Communication Layer API](https://github.com/eclipse-uprotocol/up-spec/blob/v1.6.0-alpha.3/up-l2/api.adoc) and its
   default implementation on top of the [Transport Layer API](https://github.com/eclipse-uprotocol/up-spec/blob/v1.6.0-alpha.3/up-l1/README.adoc).
   Enabled by default.
+* `http` enables conversion of [`UUri`]s from/to the [`http::Uri`](https://docs.rs/http/latest/http/uri/struct.Uri.html) type.
+* `serde` enables (de-)serialization of [`UUri`]s from/to their URI string representation using [serde](https://serde.rs).
 * `udiscovery` enables support for types required to interact with [uDiscovery service](https://raw.githubusercontent.com/eclipse-uprotocol/up-spec/v1.6.0-alpha.3/up-l3/udiscovery/v3/README.adoc)
   implementations.
 * `usubscription` enables support for types required to interact with [uSubscription service](https://raw.githubusercontent.com/eclipse-uprotocol/up-spec/v1.6.0-alpha.3/up-l3/usubscription/v3/README.adoc)
   implementations. Enabled by default.
 * `utwin` enables support for types required to interact with [uTwin service](https://raw.githubusercontent.com/eclipse-uprotocol/up-spec/v1.6.0-alpha.3/up-l3/utwin/v3/README.adoc)
   implementations.
+* `url` enables conversion of [`UUri`]s from/to the [`url::Url`](https://docs.rs/url/latest/url/struct.Url.html) type.
 * `util` provides some useful helper structs. In particular, provides a local, in-memory UTransport for exchanging messages within a single process. This transport is also used by the examples illustrating usage of the Communication Layer API.
 
 ## References
@@ -70,7 +74,9 @@ mod umessage;
 pub use umessage::{UMessage, UMessageBuilder, UMessageError};
 
 mod uri;
-pub use uri::{UUri, UUriError};
+#[cfg(feature = "ahash")]
+pub use uri::UUriMap;
+pub use uri::{UUri, UUriBuilder, UUriError, UUriKind};
 
 mod ustatus;
 pub use ustatus::{UCode, UStatus};
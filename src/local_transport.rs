@@ -20,7 +20,7 @@ use std::{collections::HashSet, sync::Arc};
 
 use tokio::sync::RwLock;
 
-use crate::{ComparableListener, UListener, UMessage, UStatus, UTransport, UUri};
+use crate::{ComparableListener, UCode, UListener, UMessage, UStatus, UTransport, UUri};
 
 #[derive(Eq, PartialEq, Hash)]
 struct RegisteredListener {
@@ -65,7 +65,8 @@ impl RegisteredListener {
 /// A [`UTransport`] that can be used to exchange messages within a single process.
 ///
 /// A message sent via [`UTransport::send`] will be dispatched to all registered listeners that
-/// match the message's source and sink filters.
+/// match the message's source and sink filters. Messages that have already expired are
+/// rejected and not dispatched at all.
 #[derive(Default)]
 pub struct LocalTransport {
     listeners: RwLock<HashSet<RegisteredListener>>,
@@ -85,6 +86,16 @@ impl LocalTransport {
 #[async_trait::async_trait]
 impl UTransport for LocalTransport {
     async fn send(&self, message: UMessage) -> Result<(), UStatus> {
+        if message
+            .attributes
+            .as_ref()
+            .is_some_and(|attribs| attribs.is_expired())
+        {
+            return Err(UStatus::fail_with_code(
+                UCode::DEADLINE_EXCEEDED,
+                "message has expired",
+            ));
+        }
         self.dispatch(message).await;
         Ok(())
     }
@@ -103,7 +114,7 @@ impl UTransport for LocalTransport {
         let mut listeners = self.listeners.write().await;
         if listeners.contains(&registered_listener) {
             Err(UStatus::fail_with_code(
-                crate::UCode::ALREADY_EXISTS,
+                UCode::ALREADY_EXISTS,
                 "listener already registered for filters",
             ))
         } else {
@@ -128,7 +139,7 @@ impl UTransport for LocalTransport {
             Ok(())
         } else {
             Err(UStatus::fail_with_code(
-                crate::UCode::NOT_FOUND,
+                UCode::NOT_FOUND,
                 "no such listener registered for filters",
             ))
         }
@@ -137,8 +148,12 @@ impl UTransport for LocalTransport {
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use super::*;
-    use crate::{utransport::MockUListener, LocalUriProvider, StaticUriProvider, UMessageBuilder};
+    use crate::{
+        utransport::MockUListener, LocalUriProvider, StaticUriProvider, UMessageBuilder, UUID,
+    };
 
     #[tokio::test]
     async fn test_send_dispatches_to_matching_listener() {
@@ -223,4 +238,66 @@ mod tests {
             )
             .await;
     }
+
+    #[tokio::test]
+    async fn test_send_dispatches_to_all_matching_listeners() {
+        const RESOURCE_ID: u16 = 0xa1b3;
+        let mut first_listener = MockUListener::new();
+        first_listener.expect_on_receive().once().return_const(());
+        let mut second_listener = MockUListener::new();
+        second_listener.expect_on_receive().once().return_const(());
+        let uri_provider = StaticUriProvider::new("my-vehicle", 0x100d, 0x02);
+        let transport = LocalTransport::default();
+
+        transport
+            .register_listener(
+                &uri_provider.get_resource_uri(RESOURCE_ID),
+                None,
+                Arc::new(first_listener),
+            )
+            .await
+            .unwrap();
+        transport
+            .register_listener(
+                &UUri::try_from("//my-vehicle/FFFF/FF/FFFF").unwrap(),
+                None,
+                Arc::new(second_listener),
+            )
+            .await
+            .unwrap();
+        let result = transport
+            .send(
+                UMessageBuilder::publish(uri_provider.get_resource_uri(RESOURCE_ID))
+                    .build()
+                    .unwrap(),
+            )
+            .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_send_fails_for_expired_message() {
+        const RESOURCE_ID: u16 = 0xa1b3;
+        let mut listener = MockUListener::new();
+        listener.expect_on_receive().never().return_const(());
+        let uri_provider = StaticUriProvider::new("my-vehicle", 0x100d, 0x02);
+        let transport = LocalTransport::default();
+
+        transport
+            .register_listener(
+                &uri_provider.get_resource_uri(RESOURCE_ID),
+                None,
+                Arc::new(listener),
+            )
+            .await
+            .unwrap();
+        // a message that has been created at the beginning of the epoch
+        let expired_message = UMessageBuilder::publish(uri_provider.get_resource_uri(RESOURCE_ID))
+            .with_message_id(UUID::build_for_timestamp(Duration::from_millis(1000)))
+            .with_ttl(100)
+            .build()
+            .unwrap();
+        let result = transport.send(expired_message).await;
+        assert!(result.is_err_and(|e| e.get_code() == UCode::DEADLINE_EXCEEDED));
+    }
 }
@@ -15,10 +15,13 @@ mod uattributesvalidator;
 mod upayloadformat;
 mod upriority;
 
+use std::time::{SystemTime, UNIX_EPOCH};
+
 pub use uattributesvalidator::*;
 pub use upriority::*;
 
 pub use crate::up_core_api::uattributes::*;
+use crate::UUID;
 
 #[derive(Debug)]
 pub enum UAttributesError {
@@ -129,4 +132,135 @@ impl UAttributes {
             .enum_value()
             .map_or(false, |v| v == UMessageType::UMESSAGE_TYPE_NOTIFICATION)
     }
+
+    /// Sets the token to use for authenticating to infrastructure and service endpoints.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UAttributes;
+    ///
+    /// let mut attribs = UAttributes::default();
+    /// attribs.with_token("my-token");
+    /// assert_eq!(attribs.token, Some("my-token".to_string()));
+    /// ```
+    pub fn with_token<T: Into<String>>(&mut self, token: T) -> &mut UAttributes {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Checks if the message that is described by these attributes should be considered expired.
+    ///
+    /// This is a shorthand for [`UAttributes::is_expired_at`] using the current system time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{UAttributes, UUID};
+    ///
+    /// let attribs = UAttributes {
+    ///   id: Some(UUID::build()).into(),
+    ///   ttl: Some(10_000),
+    ///   ..Default::default()
+    /// };
+    /// assert!(!attribs.is_expired());
+    /// ```
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(SystemTime::now())
+    }
+
+    /// Checks if the message that is described by these attributes should be considered expired
+    /// at a given point in time.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The point in time to check for.
+    ///
+    /// # Returns
+    ///
+    /// `true` if [`UAttributes::ttl`] (time-to-live) contains a value greater than 0 and at least
+    /// that many milliseconds have passed between the creation time extracted from [`UAttributes::id`]
+    /// and the given point in time. Attributes without a TTL, with a TTL of 0 or without a
+    /// (valid uProtocol) ID are never considered expired.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::{Duration, SystemTime};
+    /// use up_rust::{UAttributes, UUID};
+    ///
+    /// let attribs = UAttributes {
+    ///   id: Some(UUID::build()).into(),
+    ///   ttl: Some(500),
+    ///   ..Default::default()
+    /// };
+    /// let now = SystemTime::now();
+    /// assert!(!attribs.is_expired_at(now));
+    /// assert!(attribs.is_expired_at(now + Duration::from_millis(1000)));
+    /// ```
+    pub fn is_expired_at(&self, now: SystemTime) -> bool {
+        let ttl = match self.ttl {
+            Some(t) if t > 0 => u64::from(t),
+            _ => return false,
+        };
+        let Some(creation_time) = self.id.as_ref().and_then(UUID::get_time) else {
+            return false;
+        };
+        now.duration_since(UNIX_EPOCH)
+            .ok()
+            .and_then(|duration| u64::try_from(duration.as_millis()).ok())
+            // a message created in the future (e.g. due to clock skew) has not expired
+            .map_or(false, |now_millis| {
+                now_millis.saturating_sub(creation_time) >= ttl
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use test_case::test_case;
+
+    use super::*;
+
+    const CREATION_TIME: u64 = 1_700_000_000_000;
+
+    fn attributes_with(ttl: Option<u32>) -> UAttributes {
+        UAttributes {
+            id: Some(UUID::build_for_timestamp(Duration::from_millis(
+                CREATION_TIME,
+            )))
+            .into(),
+            ttl,
+            ..Default::default()
+        }
+    }
+
+    #[test_case(None, 10_000, false; "for message without TTL")]
+    #[test_case(Some(0), 10_000, false; "for message with TTL 0")]
+    #[test_case(Some(500), 499, false; "for message before TTL has elapsed")]
+    #[test_case(Some(500), 500, true; "for message when TTL has elapsed")]
+    #[test_case(Some(500), 10_000, true; "for message long after TTL has elapsed")]
+    fn test_is_expired_at(ttl: Option<u32>, millis_since_creation: u64, should_be_expired: bool) {
+        let attributes = attributes_with(ttl);
+        let now = UNIX_EPOCH + Duration::from_millis(CREATION_TIME + millis_since_creation);
+        assert_eq!(attributes.is_expired_at(now), should_be_expired);
+    }
+
+    #[test]
+    fn test_is_expired_at_for_message_created_in_the_future() {
+        let attributes = attributes_with(Some(500));
+        let now = UNIX_EPOCH + Duration::from_millis(CREATION_TIME - 1000);
+        assert!(!attributes.is_expired_at(now));
+    }
+
+    #[test]
+    fn test_is_expired_at_for_message_without_id() {
+        let attributes = UAttributes {
+            ttl: Some(500),
+            ..Default::default()
+        };
+        assert!(!attributes.is_expired_at(SystemTime::now()));
+    }
 }
@@ -11,11 +11,9 @@
  * SPDX-License-Identifier: Apache-2.0
  ********************************************************************************/
 
-use std::time::SystemTime;
-
 use protobuf::Enum;
 
-use crate::{UAttributes, UMessageType, UPriority, UUri, UUID};
+use crate::{UAttributes, UMessageType, UPriority, UUri};
 
 use crate::UAttributesError;
 
@@ -59,7 +57,7 @@ pub trait UAttributesValidator: Send {
     ///
     /// # Errors
     ///
-    /// Returns an error if [`UAttributes::id`] does not contain a [valid uProtocol UUID](`UUID::is_uprotocol_uuid`).
+    /// Returns an error if [`UAttributes::id`] does not contain a [valid uProtocol UUID](`crate::UUID::is_uprotocol_uuid`).
     fn validate_id(&self, attributes: &UAttributes) -> Result<(), UAttributesError> {
         if attributes
             .id
@@ -81,31 +79,15 @@ pub trait UAttributesValidator: Send {
     ///
     /// # Errors
     ///
-    /// Returns an error if [`UAttributes::ttl`] (time-to-live) contains a value greater than 0, but
-    /// * the message has expired according to the timestamp extracted from [`UAttributes::id`] and the time-to-live value, or
-    /// * the current system time cannot be determined.
+    /// Returns an error if [`UAttributes::ttl`] (time-to-live) contains a value greater than 0 and
+    /// the message has expired according to the timestamp extracted from [`UAttributes::id`] and
+    /// the time-to-live value, see [`UAttributes::is_expired`].
     fn is_expired(&self, attributes: &UAttributes) -> Result<(), UAttributesError> {
-        let ttl = match attributes.ttl {
-            Some(t) if t > 0 => u64::from(t),
-            _ => return Ok(()),
-        };
-
-        if let Some(time) = attributes.id.as_ref().and_then(UUID::get_time) {
-            let delta = match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
-                Ok(duration) => {
-                    if let Ok(duration) = u64::try_from(duration.as_millis()) {
-                        duration - time
-                    } else {
-                        return Err(UAttributesError::validation_error("Invalid duration"));
-                    }
-                }
-                Err(e) => return Err(UAttributesError::validation_error(e.to_string())),
-            };
-            if delta >= ttl {
-                return Err(UAttributesError::validation_error("Payload is expired"));
-            }
+        if attributes.is_expired() {
+            Err(UAttributesError::validation_error("Payload is expired"))
+        } else {
+            Ok(())
         }
-        Ok(())
     }
 
     /// Verifies that a set of attributes contains a valid source URI.
@@ -427,6 +409,41 @@ impl RequestValidator {
             )),
         }
     }
+
+    /// Verifies that a set of attributes representing an RPC request contain a token.
+    ///
+    /// This check is not part of [`RequestValidator::validate`] because the token is optional
+    /// in general. Deployments that require all requests to be authenticated should invoke this
+    /// function in addition to the standard validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`UAttributes::token`] is empty or contains an empty string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{RequestValidator, UAttributes};
+    ///
+    /// let mut attributes = UAttributes::default();
+    /// assert!(RequestValidator.validate_token(&attributes).is_err());
+    ///
+    /// attributes.with_token("my-token");
+    /// assert!(RequestValidator.validate_token(&attributes).is_ok());
+    /// ```
+    pub fn validate_token(&self, attributes: &UAttributes) -> Result<(), UAttributesError> {
+        if attributes
+            .token
+            .as_ref()
+            .map_or(true, |token| token.is_empty())
+        {
+            Err(UAttributesError::validation_error(
+                "RPC request message must contain a token",
+            ))
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl UAttributesValidator for RequestValidator {
@@ -510,7 +527,7 @@ impl ResponseValidator {
     /// # Errors
     ///
     /// Returns an error if [`UAttributes::reqid`] is empty or contains a value which is not
-    /// a [valid uProtocol UUID](`UUID::is_uprotocol_uuid`).
+    /// a [valid uProtocol UUID](`crate::UUID::is_uprotocol_uuid`).
     pub fn validate_reqid(&self, attributes: &UAttributes) -> Result<(), UAttributesError> {
         if !attributes
             .reqid
@@ -627,7 +644,7 @@ impl UAttributesValidator for ResponseValidator {
 mod tests {
     use std::{
         ops::Sub,
-        time::{Duration, UNIX_EPOCH},
+        time::{Duration, SystemTime, UNIX_EPOCH},
     };
 
     use protobuf::EnumOrUnknown;
@@ -911,6 +928,28 @@ mod tests {
         }
     }
 
+    #[test_case(Some(String::from("token")), true; "succeeds for token")]
+    #[test_case(Some(String::new()), false; "fails for empty token")]
+    #[test_case(None, false; "fails for missing token")]
+    fn test_validate_token_for_rpc_request_message(token: Option<String>, expected_result: bool) {
+        let attributes = UAttributes {
+            type_: UMessageType::UMESSAGE_TYPE_REQUEST.into(),
+            id: Some(UUID::build()).into(),
+            priority: UPriority::UPRIORITY_CS4.into(),
+            source: Some(reply_to_address()).into(),
+            sink: Some(method_to_invoke()).into(),
+            ttl: Some(2000),
+            token,
+            ..Default::default()
+        };
+        // the token is optional in general
+        assert!(RequestValidator.validate(&attributes).is_ok());
+        assert_eq!(
+            RequestValidator.validate_token(&attributes).is_ok(),
+            expected_result
+        );
+    }
+
     #[test_case(Some(UUID::build()), Some(reply_to_address()), Some(method_to_invoke()), Some(UUID::build()), None, None, Some(UPriority::UPRIORITY_CS4), true; "succeeds for mandatory attributes")]
     #[test_case(Some(UUID::build()), Some(reply_to_address()), Some(method_to_invoke()), Some(UUID::build()), Some(EnumOrUnknown::from(UCode::CANCELLED)), Some(100), Some(UPriority::UPRIORITY_CS4), true; "succeeds for valid attributes")]
     #[test_case(None, Some(reply_to_address()), Some(method_to_invoke()), Some(UUID::build()), Some(EnumOrUnknown::from(UCode::CANCELLED)), Some(100), Some(UPriority::UPRIORITY_CS4), false; "fails for missing message ID")]
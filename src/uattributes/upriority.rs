@@ -11,7 +11,9 @@
  * SPDX-License-Identifier: Apache-2.0
  ********************************************************************************/
 
-use protobuf::EnumFull;
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use protobuf::{Enum, EnumFull};
 
 use crate::uattributes::UAttributesError;
 pub use crate::up_core_api::uattributes::UPriority;
@@ -80,3 +82,115 @@ impl UPriority {
             .ok_or_else(|| UAttributesError::parsing_error(format!("unknown priority [{}]", prio)))
     }
 }
+
+impl PartialOrd for UPriority {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UPriority {
+    /// Compares priorities by their urgency.
+    ///
+    /// A priority is considered greater than another one if messages having the priority
+    /// should be processed before messages having the other priority, i.e. `CS0` < `CS1` < ... < `CS6`.
+    ///
+    /// [`UPriority::UPRIORITY_UNSPECIFIED`] is considered less than `CS0`. Note that this does not
+    /// reflect the priority that is assumed for messages which do not specify a priority at all,
+    /// which is `CS1`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UPriority;
+    ///
+    /// assert!(UPriority::UPRIORITY_CS4 > UPriority::UPRIORITY_CS1);
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value().cmp(&other.value())
+    }
+}
+
+impl Display for UPriority {
+    /// Writes this priority's code, e.g. `CS2`.
+    ///
+    /// [`UPriority::UPRIORITY_UNSPECIFIED`] does not have a code and is therefore written as an
+    /// empty string, which cannot be parsed back into a priority using [`FromStr`].
+    ///
+    /// See [`UPriority::to_priority_code`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_priority_code())
+    }
+}
+
+impl FromStr for UPriority {
+    type Err = UAttributesError;
+
+    /// Gets the priority for a code, e.g. `CS2`.
+    ///
+    /// See [`UPriority::try_from_priority_code`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UPriority;
+    ///
+    /// let priority: UPriority = "CS5".parse().unwrap();
+    /// assert_eq!(priority, UPriority::UPRIORITY_CS5);
+    /// assert_eq!(priority.to_string(), "CS5");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_priority_code(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const PRIORITIES: [UPriority; 7] = [
+        UPriority::UPRIORITY_CS0,
+        UPriority::UPRIORITY_CS1,
+        UPriority::UPRIORITY_CS2,
+        UPriority::UPRIORITY_CS3,
+        UPriority::UPRIORITY_CS4,
+        UPriority::UPRIORITY_CS5,
+        UPriority::UPRIORITY_CS6,
+    ];
+
+    #[test]
+    fn test_ordering_follows_class_of_service() {
+        PRIORITIES.windows(2).for_each(|pair| {
+            assert!(pair[0] < pair[1]);
+        });
+        assert!(UPriority::UPRIORITY_CS4 > UPriority::UPRIORITY_CS1);
+        assert_eq!(PRIORITIES.iter().max(), Some(&UPriority::UPRIORITY_CS6));
+        assert!(UPriority::UPRIORITY_UNSPECIFIED < UPriority::UPRIORITY_CS0);
+    }
+
+    #[test]
+    fn test_string_round_trip() {
+        PRIORITIES.iter().for_each(|priority| {
+            let code = priority.to_string();
+            assert_eq!(code, format!("CS{}", priority.value() - 1));
+            assert_eq!(code.parse::<UPriority>().unwrap(), *priority);
+        });
+    }
+
+    #[test]
+    fn test_unspecified_priority_does_not_round_trip() {
+        let code = UPriority::UPRIORITY_UNSPECIFIED.to_string();
+        assert!(code.is_empty());
+        assert!(code.parse::<UPriority>().is_err());
+    }
+
+    #[test_case(""; "for empty string")]
+    #[test_case("cs1"; "for lower case code")]
+    #[test_case("CS7"; "for unknown class of service")]
+    fn test_from_str_fails(code: &str) {
+        assert!(code
+            .parse::<UPriority>()
+            .is_err_and(|e| matches!(e, UAttributesError::ParsingError(_))));
+    }
+}
@@ -11,11 +11,14 @@
  * SPDX-License-Identifier: Apache-2.0
  ********************************************************************************/
 
+use std::{fmt::Display, str::FromStr};
+
 use protobuf::EnumFull;
 
 use crate::uattributes::UAttributesError;
 pub use crate::up_core_api::uattributes::UMessageType;
 use crate::up_core_api::uoptions::exts::ce_name;
+use crate::UUri;
 
 impl UMessageType {
     /// Gets this message type's CloudEvent type name.
@@ -59,13 +62,94 @@ impl UMessageType {
                 UAttributesError::parsing_error(format!("unknown message type: {}", type_string))
             })
     }
+
+    /// Checks if a message of this type may be sent from a given source to a given sink.
+    ///
+    /// Only the shape of the URIs is checked, i.e. whether they refer to the kind of
+    /// resource that messages of this type are expected to originate from or be sent to:
+    ///
+    /// * A Publish message originates from a topic and has no sink.
+    /// * A Notification originates from a topic (or any other resource but the RPC response address)
+    ///   and is sent to a uEntity's notification destination.
+    /// * An RPC Request originates from the reply-to address and is sent to an RPC method.
+    /// * An RPC Response originates from an RPC method and is sent to the reply-to address.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The URI that a message of this type originates from.
+    /// * `sink` - The URI that a message of this type is sent to.
+    ///
+    /// # Returns
+    ///
+    /// `false` if the URIs do not fit this message type or if this is [`UMessageType::UMESSAGE_TYPE_UNSPECIFIED`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{UMessageType, UUri};
+    ///
+    /// let method = UUri::try_from("//my-vehicle/4210/5/64AB").unwrap();
+    /// let reply_to = UUri::try_from("//my-vehicle/1000/1/0").unwrap();
+    ///
+    /// assert!(UMessageType::UMESSAGE_TYPE_REQUEST.is_compatible_with(&reply_to, Some(&method)));
+    /// assert!(!UMessageType::UMESSAGE_TYPE_REQUEST.is_compatible_with(&method, Some(&reply_to)));
+    /// ```
+    pub fn is_compatible_with(&self, source: &UUri, sink: Option<&UUri>) -> bool {
+        match self {
+            UMessageType::UMESSAGE_TYPE_PUBLISH => source.is_event() && sink.is_none(),
+            UMessageType::UMESSAGE_TYPE_NOTIFICATION => {
+                !source.is_rpc_response()
+                    && sink.is_some_and(|uri| uri.is_notification_destination())
+            }
+            UMessageType::UMESSAGE_TYPE_REQUEST => {
+                source.is_rpc_response() && sink.is_some_and(|uri| uri.is_rpc_method())
+            }
+            UMessageType::UMESSAGE_TYPE_RESPONSE => {
+                source.is_rpc_method() && sink.is_some_and(|uri| uri.is_rpc_response())
+            }
+            UMessageType::UMESSAGE_TYPE_UNSPECIFIED => false,
+        }
+    }
+}
+
+impl Display for UMessageType {
+    /// Writes this message type's CloudEvent type name.
+    ///
+    /// [`UMessageType::UMESSAGE_TYPE_UNSPECIFIED`] does not have a type name and is therefore
+    /// written as an empty string, which cannot be parsed back into a message type using [`FromStr`].
+    ///
+    /// See [`UMessageType::to_cloudevent_type`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_cloudevent_type())
+    }
+}
+
+impl FromStr for UMessageType {
+    type Err = UAttributesError;
+
+    /// Gets the message type for a CloudEvent type name.
+    ///
+    /// See [`UMessageType::try_from_cloudevent_type`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UMessageType;
+    ///
+    /// let message_type: UMessageType = "req.v1".parse().unwrap();
+    /// assert_eq!(message_type, UMessageType::UMESSAGE_TYPE_REQUEST);
+    /// assert_eq!(message_type.to_string(), "req.v1");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_cloudevent_type(s)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use test_case::test_case;
 
-    use crate::{UAttributesError, UMessageType};
+    use crate::{UAttributesError, UMessageType, UUri};
 
     const TYPE_PUBLISH: &str = "pub.v1";
     const TYPE_NOTIFICATION: &str = "not.v1";
@@ -100,4 +184,48 @@ mod tests {
             ))
         }
     }
+
+    #[test_case(UMessageType::UMESSAGE_TYPE_PUBLISH; "for PUBLISH")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_NOTIFICATION; "for NOTIFICATION")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_REQUEST; "for REQUEST")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_RESPONSE; "for RESPONSE")]
+    fn test_string_round_trip(message_type: UMessageType) {
+        let type_string = message_type.to_string();
+        assert_eq!(type_string.parse::<UMessageType>().unwrap(), message_type);
+    }
+
+    #[test]
+    fn test_unspecified_message_type_does_not_round_trip() {
+        let type_string = UMessageType::UMESSAGE_TYPE_UNSPECIFIED.to_string();
+        assert!(type_string.is_empty());
+        assert!(type_string
+            .parse::<UMessageType>()
+            .is_err_and(|e| matches!(e, UAttributesError::ParsingError(_))));
+    }
+
+    #[test_case(UMessageType::UMESSAGE_TYPE_PUBLISH, "//my-vehicle/A100/1/8001", None, true; "succeeds for PUBLISH")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_PUBLISH, "//my-vehicle/A100/1/8001", Some("//my-vehicle/B200/1/0"), false; "fails for PUBLISH with sink")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_PUBLISH, "//my-vehicle/A100/1/64AB", None, false; "fails for PUBLISH from RPC method")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_NOTIFICATION, "//my-vehicle/A100/1/8001", Some("//my-vehicle/B200/1/0"), true; "succeeds for NOTIFICATION")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_NOTIFICATION, "//my-vehicle/A100/1/8001", None, false; "fails for NOTIFICATION without sink")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_NOTIFICATION, "//my-vehicle/A100/1/0", Some("//my-vehicle/B200/1/0"), false; "fails for NOTIFICATION from RPC response address")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_REQUEST, "//my-vehicle/A100/1/0", Some("//my-vehicle/B200/1/64AB"), true; "succeeds for REQUEST")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_REQUEST, "//my-vehicle/A100/1/0", Some("//my-vehicle/B200/1/8001"), false; "fails for REQUEST to topic")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_REQUEST, "//my-vehicle/A100/1/0", None, false; "fails for REQUEST without sink")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_RESPONSE, "//my-vehicle/B200/1/64AB", Some("//my-vehicle/A100/1/0"), true; "succeeds for RESPONSE")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_RESPONSE, "//my-vehicle/A100/1/0", Some("//my-vehicle/B200/1/64AB"), false; "fails for RESPONSE with swapped URIs")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_UNSPECIFIED, "//my-vehicle/A100/1/8001", None, false; "fails for UNSPECIFIED")]
+    fn test_is_compatible_with(
+        message_type: UMessageType,
+        source: &str,
+        sink: Option<&str>,
+        expected_result: bool,
+    ) {
+        let source = UUri::try_from(source).unwrap();
+        let sink = sink.map(|uri| UUri::try_from(uri).unwrap());
+        assert_eq!(
+            message_type.is_compatible_with(&source, sink.as_ref()),
+            expected_result
+        );
+    }
 }
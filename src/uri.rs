@@ -14,11 +14,17 @@
 // [impl->dsn~uri-data-model-naming~1]
 // [impl->req~uri-data-model-proto~1]
 
+mod builder;
+
+use std::cmp::Ordering;
 use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
+use protobuf::{UnknownFields, UnknownValueRef};
 use uriparse::{Authority, URIReference};
 
+pub use builder::UUriBuilder;
+
 pub use crate::up_core_api::uri::UUri;
 
 pub(crate) const WILDCARD_AUTHORITY: &str = "*";
@@ -26,10 +32,55 @@ pub(crate) const WILDCARD_ENTITY_ID: u32 = 0x0000_FFFF;
 pub(crate) const WILDCARD_ENTITY_VERSION: u32 = 0x0000_00FF;
 pub(crate) const WILDCARD_RESOURCE_ID: u32 = 0x0000_FFFF;
 
+// A URI with an authority name of 128 characters only amounts to about 160 bytes,
+// so this limit still leaves plenty of room for percent-encoded authority names.
+pub(crate) const DEFAULT_MAX_URI_LENGTH: usize = 4096;
+
 pub(crate) const RESOURCE_ID_RESPONSE: u32 = 0;
 pub(crate) const RESOURCE_ID_MIN_EVENT: u32 = 0x8000;
 
-#[derive(Debug)]
+/// A map using [`UUri`]s as keys, which uses [ahash](https://docs.rs/ahash) for hashing.
+///
+/// `ahash` is considerably faster than the SipHash algorithm used by the standard library's
+/// default hasher, while still providing resistance against HashDoS attacks.
+///
+/// Note that `UUriMap::new()` and `UUriMap::with_capacity()` cannot be used, because the
+/// standard library provides these functions only for maps using its default hasher.
+/// A map can be created using `UUriMap::default()` or `UUriMap::with_capacity_and_hasher()`
+/// instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use up_rust::{UUri, UUriMap};
+///
+/// let mut subscriptions = UUriMap::default();
+/// subscriptions.insert(UUri::try_from("//vcu/A100/1/8001").unwrap(), "door status");
+/// assert!(subscriptions.contains_key(&UUri::try_from("//vcu/A100/1/8001").unwrap()));
+///
+/// let routes: UUriMap<&str> = UUriMap::with_capacity_and_hasher(16, Default::default());
+/// assert!(routes.capacity() >= 16);
+/// ```
+#[cfg(feature = "ahash")]
+pub type UUriMap<V> = std::collections::HashMap<UUri, V, ahash::RandomState>;
+
+/// The kinds of resources that a [`UUri`] can refer to.
+///
+/// The kind is determined by the URI's resource ID, see [`UUri::kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UUriKind {
+    /// The URI is equal to `UUri::default()`.
+    Empty,
+    /// The URI refers to the address that RPC responses (and notifications) are sent to
+    /// (resource ID 0).
+    RpcResponse,
+    /// The URI refers to a service method (resource ID `[0x0001, 0x7FFF]`).
+    RpcMethod,
+    /// The URI refers to a topic that events are published to (resource ID `[0x8000, 0xFFFF]`).
+    Topic,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum UUriError {
     SerializationError(String),
     ValidationError(String),
@@ -101,9 +152,10 @@ impl FromStr for UUri {
 
     /// Attempts to parse a `String` into a `UUri`.
     ///
-    /// As part of the parsing, the _authority_ of the URI is getting normalized. This means that all characters
-    /// are converted to lowercase, no bytes that are in the unreserved character set remain percent-encoded,
-    /// and all alphabetical characters in percent-encodings are converted to uppercase.
+    /// If the _authority_ of the URI is a registered name, it is taken over as is, i.e. its case and
+    /// any percent-encoded characters are preserved. An IP address is converted to its canonical
+    /// form instead, e.g. `[2001:DB8:0::1]` becomes `[2001:db8::1]`. Use
+    /// [`UUri::from_str_normalized`] for getting a URI with a normalized authority.
     ///
     /// # Arguments
     ///
@@ -130,81 +182,8 @@ impl FromStr for UUri {
     /// let uri_from = UUri::from_str("//VIN.vehicles/A8000/2/1A50").unwrap();
     /// assert_eq!(uri, uri_from);
     /// ````
-    // [impl->dsn~uri-authority-name-length~1]
-    // [impl->dsn~uri-scheme~1]
-    // [impl->dsn~uri-host-only~2]
-    // [impl->dsn~uri-authority-mapping~1]
-    // [impl->dsn~uri-path-mapping~1]
-    // [impl->req~uri-serialization~1]
     fn from_str(uri: &str) -> Result<Self, Self::Err> {
-        if uri.is_empty() {
-            return Err(UUriError::serialization_error("URI is empty"));
-        }
-        let parsed_uri = URIReference::try_from(uri)
-            .map_err(|e| UUriError::serialization_error(e.to_string()))?;
-
-        if let Some(scheme) = parsed_uri.scheme() {
-            if scheme.ne("up") {
-                return Err(UUriError::serialization_error(
-                    "uProtocol URI must use 'up' scheme",
-                ));
-            }
-        }
-        if parsed_uri.has_query() {
-            return Err(UUriError::serialization_error(
-                "uProtocol URI must not contain query",
-            ));
-        }
-        if parsed_uri.has_fragment() {
-            return Err(UUriError::serialization_error(
-                "uProtocol URI must not contain fragment",
-            ));
-        }
-        let authority_name = parsed_uri
-            .authority()
-            .map_or(Ok(String::default()), Self::verify_parsed_authority)?;
-
-        let path_segments = parsed_uri.path().segments();
-        if path_segments.len() != 3 {
-            return Err(UUriError::serialization_error(
-                "uProtocol URI must contain entity ID, entity version and resource ID",
-            ));
-        }
-        let entity = path_segments[0].as_str();
-        if entity.is_empty() {
-            return Err(UUriError::serialization_error(
-                "URI must contain non-empty entity ID",
-            ));
-        }
-        let ue_id = u32::from_str_radix(entity, 16).map_err(|e| {
-            UUriError::serialization_error(format!("Cannot parse entity ID: {}", e))
-        })?;
-        let version = path_segments[1].as_str();
-        if version.is_empty() {
-            return Err(UUriError::serialization_error(
-                "URI must contain non-empty entity version",
-            ));
-        }
-        let ue_version_major = u8::from_str_radix(version, 16).map_err(|e| {
-            UUriError::serialization_error(format!("Cannot parse entity version: {}", e))
-        })?;
-        let resource = path_segments[2].as_str();
-        if resource.is_empty() {
-            return Err(UUriError::serialization_error(
-                "URI must contain non-empty resource ID",
-            ));
-        }
-        let resource_id = u16::from_str_radix(resource, 16).map_err(|e| {
-            UUriError::serialization_error(format!("Cannot parse resource ID: {}", e))
-        })?;
-
-        Ok(UUri {
-            authority_name,
-            ue_id,
-            ue_version_major: ue_version_major as u32,
-            resource_id: resource_id as u32,
-            ..Default::default()
-        })
+        UUri::from_str_with_limit(uri, DEFAULT_MAX_URI_LENGTH)
     }
 }
 
@@ -278,6 +257,156 @@ impl TryFrom<&str> for UUri {
     }
 }
 
+#[cfg(feature = "http")]
+impl TryFrom<http::Uri> for UUri {
+    type Error = UUriError;
+
+    /// Attempts to create a `UUri` from an [`http::Uri`].
+    ///
+    /// The HTTP URI's host is mapped to the authority name and its path to the entity ID,
+    /// entity version and resource ID. The HTTP URI must either use the `up` scheme or
+    /// consist of a path only. URIs containing a port, userinfo, query or fragment are rejected
+    /// because they cannot be represented by a `UUri`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UUriError::SerializationError`] if the HTTP URI is not a valid uProtocol URI.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// let http_uri: http::Uri = "up://vcu.vin/A100/1/2".parse().unwrap();
+    /// let uuri = UUri::try_from(http_uri).unwrap();
+    /// assert_eq!(uuri, UUri::try_from_parts("vcu.vin", 0xA100, 0x01, 0x0002).unwrap());
+    /// ```
+    fn try_from(uri: http::Uri) -> Result<Self, Self::Error> {
+        UUri::from_str(uri.to_string().as_str())
+    }
+}
+
+#[cfg(feature = "http")]
+impl TryFrom<&UUri> for http::Uri {
+    type Error = UUriError;
+
+    /// Attempts to create an [`http::Uri`] from a `UUri`.
+    ///
+    /// Local URIs are mapped to an HTTP URI consisting of a path only, because an HTTP URI
+    /// may not contain a scheme without an authority. All other URIs are mapped using the `up` scheme.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UUriError::SerializationError`] if the `UUri`'s authority name is not
+    /// a valid HTTP URI authority.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// let uuri = UUri::try_from_parts("vcu.vin", 0xA100, 0x01, 0x0002).unwrap();
+    /// let http_uri = http::Uri::try_from(&uuri).unwrap();
+    /// assert_eq!(http_uri.to_string(), "up://vcu.vin/A100/1/2");
+    /// ```
+    fn try_from(uri: &UUri) -> Result<Self, Self::Error> {
+        uri.to_uri(!uri.has_empty_authority())
+            .parse::<http::Uri>()
+            .map_err(|e| UUriError::serialization_error(format!("invalid HTTP URI: {}", e)))
+    }
+}
+
+#[cfg(feature = "url")]
+impl TryFrom<url::Url> for UUri {
+    type Error = UUriError;
+
+    /// Attempts to create a `UUri` from a [`url::Url`].
+    ///
+    /// The URL's host is mapped to the authority name and its path to the entity ID,
+    /// entity version and resource ID. The URL must use the `up` scheme. URLs containing
+    /// a port, userinfo, query or fragment are rejected because they cannot be represented by a `UUri`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UUriError::SerializationError`] if the URL is not a valid uProtocol URI.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// let url = url::Url::parse("up://vcu.vin/A100/1/2").unwrap();
+    /// let uuri = UUri::try_from(url).unwrap();
+    /// assert_eq!(uuri, UUri::try_from_parts("vcu.vin", 0xA100, 0x01, 0x0002).unwrap());
+    /// ```
+    fn try_from(url: url::Url) -> Result<Self, Self::Error> {
+        UUri::from_str(url.as_str())
+    }
+}
+
+#[cfg(feature = "url")]
+impl TryFrom<&UUri> for url::Url {
+    type Error = UUriError;
+
+    /// Attempts to create a [`url::Url`] from a `UUri`.
+    ///
+    /// The URL always uses the `up` scheme, i.e. it has the shape `up://authority/entity/version/resource`.
+    /// Local URIs are mapped to a URL without a host, i.e. `up:/entity/version/resource`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UUriError::SerializationError`] if the `UUri`'s authority name is not
+    /// a valid URL host.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// let uuri = UUri::try_from_parts("vcu.vin", 0xA100, 0x01, 0x0002).unwrap();
+    /// let url = url::Url::try_from(&uuri).unwrap();
+    /// assert_eq!(url.as_str(), "up://vcu.vin/A100/1/2");
+    ///
+    /// let local_uuri = UUri::try_from_parts("", 0xA100, 0x01, 0x0002).unwrap();
+    /// let local_url = url::Url::try_from(&local_uuri).unwrap();
+    /// assert_eq!(local_url.as_str(), "up:/A100/1/2");
+    /// ```
+    fn try_from(uri: &UUri) -> Result<Self, Self::Error> {
+        url::Url::parse(&uri.to_uri(true))
+            .map_err(|e| UUriError::serialization_error(format!("invalid URL: {}", e)))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for UUri {
+    /// Serializes this URI to its URI string representation.
+    ///
+    /// The URI string is created using [`UUri::to_uri`], without including the uProtocol scheme.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_uri(false))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for UUri {
+    /// Deserializes a URI from its URI string representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string cannot be parsed using [`UUri::from_str`]. The error
+    /// contains the underlying [`UUriError`]'s message.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let uri = String::deserialize(deserializer)?;
+        UUri::from_str(&uri).map_err(serde::de::Error::custom)
+    }
+}
+
 impl Hash for UUri {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.authority_name.hash(state);
@@ -285,55 +414,368 @@ impl Hash for UUri {
         self.ue_version_major.hash(state);
         self.resource_id.hash(state);
     }
-}
-
-impl Eq for UUri {}
+}
+
+impl Eq for UUri {}
+
+impl PartialOrd for UUri {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UUri {
+    /// Compares this URI to another URI.
+    ///
+    /// URIs are ordered by authority name first, followed by entity ID, entity major version
+    /// and resource ID. Local URIs (having an empty authority name) therefore come before
+    /// remote URIs. URIs that only differ in unknown (protobuf) fields are ordered by those
+    /// fields, so that the ordering is consistent with `PartialEq`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// let local_uri = UUri::try_from("/A100/1/8001").unwrap();
+    /// let remote_uri = UUri::try_from("//my-vehicle/A100/1/1").unwrap();
+    /// assert!(local_uri < remote_uri);
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.authority_name
+            .cmp(&other.authority_name)
+            .then(self.ue_id.cmp(&other.ue_id))
+            .then(self.ue_version_major.cmp(&other.ue_version_major))
+            .then(self.resource_id.cmp(&other.resource_id))
+            // URIs that have been decoded from protobuf may also differ in unknown fields,
+            // which are taken into account by `PartialEq`
+            .then_with(|| {
+                cmp_unknown_fields(
+                    self.special_fields.unknown_fields(),
+                    other.special_fields.unknown_fields(),
+                )
+            })
+    }
+}
+
+/// Compares two sets of unknown fields in a deterministic way.
+///
+/// The fields are compared by field number first and then by their values. Sets containing the
+/// same values are further ordered by whether they have ever been initialized: `UnknownFields`'
+/// `PartialEq` considers an unset instance (as created by `UnknownFields::new`) different from an
+/// initialized but empty one (e.g. after `UnknownFields::clear`), so the unset instance comes first.
+fn cmp_unknown_fields(fields: &UnknownFields, other_fields: &UnknownFields) -> Ordering {
+    fn sorted_values(fields: &UnknownFields) -> Vec<(u32, u8, u64, &[u8])> {
+        let mut values: Vec<_> = fields
+            .iter()
+            .map(|(number, value)| match value {
+                UnknownValueRef::Fixed32(v) => (number, 0, u64::from(v), &[][..]),
+                UnknownValueRef::Fixed64(v) => (number, 1, v, &[][..]),
+                UnknownValueRef::Varint(v) => (number, 2, v, &[][..]),
+                UnknownValueRef::LengthDelimited(bytes) => (number, 3, 0, bytes),
+            })
+            .collect();
+        // the sort needs to be stable in order to retain the order of multiple
+        // values for the same field number
+        values.sort_by_key(|(number, ..)| *number);
+        values
+    }
+
+    fn is_initialized(fields: &UnknownFields) -> bool {
+        *fields != UnknownFields::new()
+    }
+
+    if fields == other_fields {
+        return Ordering::Equal;
+    }
+    sorted_values(fields)
+        .cmp(&sorted_values(other_fields))
+        .then_with(|| is_initialized(fields).cmp(&is_initialized(other_fields)))
+}
+
+impl UUri {
+    /// Serializes this UUri to a URI string.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_scheme` - Indicates whether to include the uProtocol scheme (`up`) in the URI.
+    ///
+    /// # Returns
+    ///
+    /// The URI as defined by the [uProtocol Specification](https://github.com/eclipse-uprotocol/up-spec).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// let uuri = UUri {
+    ///     authority_name: String::from("VIN.vehicles"),
+    ///     ue_id: 0x0000_800A,
+    ///     ue_version_major: 0x02,
+    ///     resource_id: 0x0000_1a50,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let uri_string = uuri.to_uri(true);
+    /// assert_eq!(uri_string, "up://VIN.vehicles/800A/2/1A50");
+    /// ````
+    // [impl->dsn~uri-authority-mapping~1]
+    // [impl->dsn~uri-path-mapping~1]
+    // [impl->req~uri-serialization~1]
+    pub fn to_uri(&self, include_scheme: bool) -> String {
+        let mut output = String::default();
+        if include_scheme {
+            output.push_str("up:");
+        }
+        if !self.authority_name.is_empty() {
+            output.push_str("//");
+            output.push_str(&self.authority_name);
+        }
+        let uri = format!(
+            "/{:X}/{:X}/{:X}",
+            self.ue_id, self.ue_version_major, self.resource_id
+        );
+        output.push_str(&uri);
+        output
+    }
+
+    /// Creates an abbreviated string representation of this UUri for logging purposes.
+    ///
+    /// The representation corresponds to the output of [`UUri::to_uri`] without the scheme,
+    /// except for authority names longer than `max_authority_length` characters, which are
+    /// truncated and suffixed with `…`.
+    ///
+    /// The resulting string is intended for human readers only and must not be used for
+    /// exchanging the URI with other components, because it cannot be parsed back into the original URI.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_authority_length` - The maximum number of characters of the authority name to include.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// let uuri = UUri::try_from_parts("1HGBH41JXMN109186", 0x800A, 0x02, 0x1A50).unwrap();
+    /// assert_eq!(uuri.short_display(8), "//1HGBH41J…/800A/2/1A50");
+    /// assert_eq!(uuri.short_display(32), "//1HGBH41JXMN109186/800A/2/1A50");
+    /// ````
+    pub fn short_display(&self, max_authority_length: usize) -> String {
+        if self.authority_name.chars().count() <= max_authority_length {
+            return self.to_uri(false);
+        }
+        let abbreviated_authority = self
+            .authority_name
+            .chars()
+            .take(max_authority_length)
+            .collect::<String>();
+        format!(
+            "//{}…/{:X}/{:X}/{:X}",
+            abbreviated_authority, self.ue_id, self.ue_version_major, self.resource_id
+        )
+    }
+
+    /// Attempts to parse a `String` into a `UUri`, rejecting input that exceeds a given length.
+    ///
+    /// The length of the input is checked before any parsing takes place. This allows for
+    /// cheaply rejecting (maliciously) large strings received from untrusted sources.
+    /// [`UUri::from_str`] uses this function with a limit of 4096 bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The `String` to be converted into a `UUri`.
+    /// * `max_length` - The maximum number of bytes that the given string may consist of.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UUriError::SerializationError`] if the given string is longer than `max_length` bytes
+    /// or if it is not a valid uProtocol URI.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// assert!(UUri::from_str_with_limit("//VIN.vehicles/A8000/2/1A50", 64).is_ok());
+    /// assert!(UUri::from_str_with_limit("//VIN.vehicles/A8000/2/1A50", 16).is_err());
+    /// ````
+    // [impl->dsn~uri-authority-name-length~1]
+    // [impl->dsn~uri-scheme~1]
+    // [impl->dsn~uri-host-only~2]
+    // [impl->dsn~uri-authority-mapping~1]
+    // [impl->dsn~uri-path-mapping~1]
+    // [impl->req~uri-serialization~1]
+    pub fn from_str_with_limit(uri: &str, max_length: usize) -> Result<Self, UUriError> {
+        if uri.is_empty() {
+            return Err(UUriError::serialization_error("URI is empty"));
+        }
+        if uri.len() > max_length {
+            return Err(UUriError::serialization_error(format!(
+                "URI exceeds maximum length of {} bytes",
+                max_length
+            )));
+        }
+        let parsed_uri = URIReference::try_from(uri)
+            .map_err(|e| UUriError::serialization_error(e.to_string()))?;
+
+        if let Some(scheme) = parsed_uri.scheme() {
+            // schemes are case-insensitive (RFC 3986, section 3.1),
+            // uriparse's Scheme takes this into account when comparing
+            if scheme.ne("up") {
+                return Err(UUriError::serialization_error(
+                    "uProtocol URI must use 'up' scheme",
+                ));
+            }
+        }
+        if parsed_uri.has_query() {
+            return Err(UUriError::serialization_error(
+                "uProtocol URI must not contain query",
+            ));
+        }
+        if parsed_uri.has_fragment() {
+            return Err(UUriError::serialization_error(
+                "uProtocol URI must not contain fragment",
+            ));
+        }
+        let authority_name = parsed_uri
+            .authority()
+            .map_or(Ok(String::default()), Self::verify_parsed_authority)?;
+
+        let path_segments = parsed_uri.path().segments();
+        // a duplicate scheme like in "up:up:/A100/1/2" can only end up in the (rootless) path
+        // of a URI that has a scheme but no authority
+        if parsed_uri.scheme().is_some()
+            && parsed_uri.authority().is_none()
+            && !parsed_uri.path().is_absolute()
+            && path_segments
+                .first()
+                .is_some_and(|segment| segment.as_str().contains(':'))
+        {
+            return Err(UUriError::serialization_error(
+                "uProtocol URI must not contain more than one scheme",
+            ));
+        }
+        if path_segments.len() != 3 {
+            return Err(UUriError::serialization_error(
+                "uProtocol URI must contain entity ID, entity version and resource ID",
+            ));
+        }
+        let entity = path_segments[0].as_str();
+        if entity.is_empty() {
+            return Err(UUriError::serialization_error(
+                "URI must contain non-empty entity ID",
+            ));
+        }
+        Self::verify_unsigned_segment(entity, "Entity ID")?;
+        let ue_id = u32::from_str_radix(entity, 16).map_err(|e| {
+            UUriError::serialization_error(format!("Cannot parse entity ID: {}", e))
+        })?;
+        let version = path_segments[1].as_str();
+        if version.is_empty() {
+            return Err(UUriError::serialization_error(
+                "URI must contain non-empty entity version",
+            ));
+        }
+        Self::verify_unsigned_segment(version, "Entity version")?;
+        let ue_version_major = u8::from_str_radix(version, 16).map_err(|e| {
+            UUriError::serialization_error(format!("Cannot parse entity version: {}", e))
+        })?;
+        let resource = path_segments[2].as_str();
+        if resource.is_empty() {
+            return Err(UUriError::serialization_error(
+                "URI must contain non-empty resource ID",
+            ));
+        }
+        Self::verify_unsigned_segment(resource, "Resource ID")?;
+        let resource_id = u16::from_str_radix(resource, 16).map_err(|e| {
+            UUriError::serialization_error(format!("Cannot parse resource ID: {}", e))
+        })?;
+
+        Ok(UUri {
+            authority_name,
+            ue_id,
+            ue_version_major: ue_version_major as u32,
+            resource_id: resource_id as u32,
+            ..Default::default()
+        })
+    }
 
-impl UUri {
-    /// Serializes this UUri to a URI string.
+    /// Attempts to parse multiple strings into `UUri`s.
+    ///
+    /// In contrast to parsing each string using [`UUri::from_str`] and bailing out on the first error,
+    /// this function parses all of the given strings and collects any errors that occur.
     ///
     /// # Arguments
     ///
-    /// * `include_scheme` - Indicates whether to include the uProtocol scheme (`up`) in the URI.
+    /// * `uris` - The strings to parse.
     ///
     /// # Returns
     ///
-    /// The URI as defined by the [uProtocol Specification](https://github.com/eclipse-uprotocol/up-spec).
+    /// A tuple containing the successfully parsed URIs (in the order of the given strings) and
+    /// the errors for the strings that could not be parsed, along with the (0-based) index of the
+    /// respective string.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use up_rust::UUri;
     ///
-    /// let uuri = UUri {
-    ///     authority_name: String::from("VIN.vehicles"),
-    ///     ue_id: 0x0000_800A,
-    ///     ue_version_major: 0x02,
-    ///     resource_id: 0x0000_1a50,
-    ///     ..Default::default()
-    /// };
-    ///
-    /// let uri_string = uuri.to_uri(true);
-    /// assert_eq!(uri_string, "up://VIN.vehicles/800A/2/1A50");
+    /// let (uris, errors) = UUri::parse_many(["//vcu/A100/1/8001", "invalid", "/A100/1/1"]);
+    /// assert_eq!(uris.len(), 2);
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(errors[0].0, 1);
     /// ````
-    // [impl->dsn~uri-authority-mapping~1]
-    // [impl->dsn~uri-path-mapping~1]
-    // [impl->req~uri-serialization~1]
-    pub fn to_uri(&self, include_scheme: bool) -> String {
-        let mut output = String::default();
-        if include_scheme {
-            output.push_str("up:");
+    pub fn parse_many<'a, I>(uris: I) -> (Vec<UUri>, Vec<(usize, UUriError)>)
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut parsed_uris = Vec::new();
+        let mut errors = Vec::new();
+        for (index, uri) in uris.into_iter().enumerate() {
+            match UUri::from_str(uri) {
+                Ok(parsed_uri) => parsed_uris.push(parsed_uri),
+                Err(e) => errors.push((index, e)),
+            }
         }
-        if !self.authority_name.is_empty() {
-            output.push_str("//");
-            output.push_str(&self.authority_name);
+        (parsed_uris, errors)
+    }
+
+    /// Attempts to parse a `String` into a `UUri` with a normalized authority.
+    ///
+    /// In contrast to [`UUri::from_str`], the _authority_ of the URI is getting normalized. This means that
+    /// all characters are converted to lowercase, no bytes that are in the unreserved character set remain
+    /// percent-encoded, and all alphabetical characters in percent-encodings are converted to uppercase.
+    /// Entity ID, version and resource ID are not affected by the normalization.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The `String` to be converted into a `UUri`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UUriError`] if the given string is not a valid uProtocol URI.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// let uri = UUri::from_str_normalized("//VCU.my_VIN/A8000/2/1A50").unwrap();
+    /// assert_eq!(uri.authority_name, "vcu.my_vin");
+    /// assert_eq!(uri, UUri::from_str_normalized("//vcu.MY_vin/A8000/2/1A50").unwrap());
+    /// ````
+    pub fn from_str_normalized(uri: &str) -> Result<Self, UUriError> {
+        let mut uuri = UUri::from_str(uri)?;
+        if !uuri.authority_name.is_empty() {
+            let mut authority = Authority::try_from(uuri.authority_name.as_str())
+                .map_err(|e| UUriError::serialization_error(format!("invalid authority: {}", e)))?;
+            authority.normalize();
+            let normalized_name = authority.host().to_string();
+            uuri.authority_name = normalized_name;
         }
-        let uri = format!(
-            "/{:X}/{:X}/{:X}",
-            self.ue_id, self.ue_version_major, self.resource_id
-        );
-        output.push_str(&uri);
-        output
+        Ok(uuri)
     }
 
     /// Creates a new UUri from its parts.
@@ -414,6 +856,19 @@ impl UUri {
         }
     }
 
+    // from_str_radix accepts a leading '+' and fails on a leading '-' with a
+    // rather generic error, so signs are checked for explicitly
+    fn verify_unsigned_segment(segment: &str, segment_name: &str) -> Result<(), UUriError> {
+        if segment.starts_with(['+', '-']) {
+            Err(UUriError::serialization_error(format!(
+                "{} must be an unsigned hex number without sign",
+                segment_name
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
     fn verify_major_version(major_version: u32) -> Result<u8, UUriError> {
         u8::try_from(major_version).map_err(|_e| {
             UUriError::ValidationError(
@@ -437,10 +892,14 @@ impl UUri {
     /// been deserialized from a protobuf, then this function can be used to check if all
     /// properties are compliant with the uProtocol specification.
     ///
+    /// Use [`UUri::validation_errors`] for getting each of the violations separately.
+    ///
     /// # Errors
     ///
-    /// Returns an error if this UUri is not a valid uProtocol URI. The returned error may
-    /// contain details regarding the cause of the validation to have failed.
+    /// Returns a [`UUriError::ValidationError`] if this UUri is not a valid uProtocol URI.
+    /// A single error is returned even if multiple properties are invalid. Its message then
+    /// contains the messages of all violations found, in the order returned by
+    /// [`UUri::validation_errors`], separated by `"; "`.
     ///
     /// # Examples
     ///
@@ -457,10 +916,124 @@ impl UUri {
     /// assert!(uuri.check_validity().is_ok());
     /// ```
     pub fn check_validity(&self) -> Result<(), UUriError> {
-        Self::verify_authority(self.authority_name.as_str())?;
-        Self::verify_major_version(self.ue_version_major)?;
-        Self::verify_resource_id(self.resource_id)?;
-        Ok(())
+        let error_message = self
+            .validation_errors()
+            .into_iter()
+            .map(|e| match e {
+                UUriError::SerializationError(msg) | UUriError::ValidationError(msg) => msg,
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        if error_message.is_empty() {
+            Ok(())
+        } else {
+            Err(UUriError::validation_error(error_message))
+        }
+    }
+
+    /// Checks all properties of this UUri for compliance with the uProtocol specification.
+    ///
+    /// # Returns
+    ///
+    /// An error for each property that violates the specification, in the order authority name,
+    /// entity major version and resource ID. The returned list is empty if this UUri is a valid
+    /// uProtocol URI.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// let uuri = UUri {
+    ///   authority_name: "valid_name".into(),
+    ///   ue_id: 0x1000,
+    ///   ue_version_major: 0x0101,
+    ///   resource_id: 0x1_8100,
+    ///   ..Default::default()
+    /// };
+    /// assert_eq!(uuri.validation_errors().len(), 2);
+    /// ```
+    pub fn validation_errors(&self) -> Vec<UUriError> {
+        [
+            Self::verify_authority(self.authority_name.as_str()).err(),
+            Self::verify_major_version(self.ue_version_major).err(),
+            Self::verify_resource_id(self.resource_id).err(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Determines the kind of resource that this URI refers to.
+    ///
+    /// The classification is based on the URI's resource ID only and is consistent with
+    /// [`UUri::is_rpc_response`], [`UUri::is_rpc_method`] and [`UUri::is_event`], except for
+    /// an empty URI, which is classified as [`UUriKind::Empty`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{UUri, UUriKind};
+    ///
+    /// assert_eq!(UUri::default().kind(), UUriKind::Empty);
+    /// assert_eq!(UUri::try_from("//vcu/A100/1/0").unwrap().kind(), UUriKind::RpcResponse);
+    /// assert_eq!(UUri::try_from("//vcu/A100/1/1").unwrap().kind(), UUriKind::RpcMethod);
+    /// assert_eq!(UUri::try_from("//vcu/A100/1/8001").unwrap().kind(), UUriKind::Topic);
+    /// ```
+    pub fn kind(&self) -> UUriKind {
+        if self.is_empty() {
+            UUriKind::Empty
+        } else if self.is_rpc_response() {
+            UUriKind::RpcResponse
+        } else if self.is_rpc_method() {
+            UUriKind::RpcMethod
+        } else {
+            UUriKind::Topic
+        }
+    }
+
+    /// Determines the components that this URI has in common with another URI.
+    ///
+    /// The components are compared in the order authority name, entity ID, entity major version
+    /// and resource ID. The resulting URI contains the components up to (but excluding) the first
+    /// component that differs between the two URIs. This component and all following components
+    /// are set to their wildcard values. The resulting URI therefore [matches](`UUri::matches`)
+    /// both this and the other URI.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The URI to compare this URI with.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// let uri = UUri::try_from("//vcu/A100/1/8001").unwrap();
+    /// let other = UUri::try_from("//vcu/A100/2/8001").unwrap();
+    /// let prefix = uri.common_prefix(&other);
+    /// assert_eq!(prefix, UUri::try_from("//vcu/A100/FF/FFFF").unwrap());
+    /// assert!(prefix.matches(&uri) && prefix.matches(&other));
+    /// ```
+    pub fn common_prefix(&self, other: &UUri) -> UUri {
+        let mut prefix = UUri::any();
+        if self.authority_name != other.authority_name {
+            return prefix;
+        }
+        prefix.authority_name = self.authority_name.clone();
+        if self.ue_id != other.ue_id {
+            return prefix;
+        }
+        prefix.ue_id = self.ue_id;
+        if self.ue_version_major != other.ue_version_major {
+            return prefix;
+        }
+        prefix.ue_version_major = self.ue_version_major;
+        if self.resource_id == other.resource_id {
+            prefix.resource_id = self.resource_id;
+        }
+        prefix
     }
 
     /// Checks if this URI is empty.
@@ -584,6 +1157,38 @@ impl UUri {
         self.resource_id == WILDCARD_RESOURCE_ID
     }
 
+    /// Determines how specific this UUri is when used as a pattern.
+    ///
+    /// # Returns
+    ///
+    /// The number of components that do not contain a wildcard, i.e. a value in the range
+    /// `[0, 4]`. The components considered are authority name, entity ID, entity major version and
+    /// resource ID, in line with [`UUri::has_wildcard_authority`], [`UUri::has_wildcard_entity_id`],
+    /// [`UUri::has_wildcard_version`] and [`UUri::has_wildcard_resource_id`].
+    ///
+    /// This can be used to order patterns from most to least specific, e.g. in a routing table.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// assert_eq!(UUri::any().specificity(), 0);
+    /// assert_eq!(UUri::try_from("//VIN/A100/1/FFFF").unwrap().specificity(), 3);
+    /// assert_eq!(UUri::try_from("//VIN/A100/1/8001").unwrap().specificity(), 4);
+    /// ```
+    pub fn specificity(&self) -> u8 {
+        [
+            self.has_wildcard_authority(),
+            self.has_wildcard_entity_id(),
+            self.has_wildcard_version(),
+            self.has_wildcard_resource_id(),
+        ]
+        .into_iter()
+        .filter(|is_wildcard| !is_wildcard)
+        .count() as u8
+    }
+
     /// Verifies that this UUri does not contain any wildcards.
     ///
     /// # Errors
@@ -799,6 +1404,58 @@ impl UUri {
         }
     }
 
+    /// Verifies that the source of an RPC response corresponds to the method that has been
+    /// invoked by the request that the response is for.
+    ///
+    /// The response's source must be the invoked method itself. If the request has been
+    /// sent to a method without specifying an entity instance, the response may originate
+    /// from any instance of the entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `request_method` - The method that has been invoked, i.e. the request's sink.
+    /// * `response_source` - The source of the response.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UUriError::ValidationError`] if any of the URIs
+    /// [do not refer to a service method](Self::verify_rpc_method)
+    /// or if the response's source does not correspond to the invoked method.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// let method = UUri::try_from("//my-vehicle/A100/1/7FFF").unwrap();
+    ///
+    /// let response_source = UUri::try_from("//my-vehicle/A100/1/7FFF").unwrap();
+    /// assert!(UUri::verify_rpc_response_matches_request(&method, &response_source).is_ok());
+    ///
+    /// let response_source = UUri::try_from("//my-vehicle/B200/1/7FFF").unwrap();
+    /// assert!(UUri::verify_rpc_response_matches_request(&method, &response_source).is_err());
+    /// ```
+    pub fn verify_rpc_response_matches_request(
+        request_method: &UUri,
+        response_source: &UUri,
+    ) -> Result<(), UUriError> {
+        request_method
+            .verify_rpc_method()
+            .map_err(|e| UUriError::validation_error(format!("Invalid request method: {}", e)))?;
+        response_source
+            .verify_rpc_method()
+            .map_err(|e| UUriError::validation_error(format!("Invalid response source: {}", e)))?;
+        if request_method.matches(response_source) {
+            Ok(())
+        } else {
+            Err(UUriError::validation_error(format!(
+                "Response source [{}] does not match invoked method [{}]",
+                response_source.to_uri(false),
+                request_method.to_uri(false)
+            )))
+        }
+    }
+
     fn matches_authority(&self, candidate: &UUri) -> bool {
         self.authority_name == WILDCARD_AUTHORITY || self.authority_name == candidate.authority_name
     }
@@ -857,6 +1514,14 @@ mod tests {
     use protobuf::Message;
     use test_case::test_case;
 
+    #[test]
+    fn test_error_can_be_cloned() {
+        let error = UUri::from_str("custom://vcu/A100/1/1").unwrap_err();
+        let cloned_error = error.clone();
+        assert_eq!(error, cloned_error);
+        assert_ne!(error, UUriError::validation_error("other"));
+    }
+
     // [utest->dsn~uri-authority-name-length~1]
     // [utest->dsn~uri-host-only~2]
     #[test_case(UUri {
@@ -915,6 +1580,16 @@ mod tests {
     #[test_case("up://MYVIN:1000/1A23/1/A13"; "for authority with port")]
     #[test_case("up://user:pwd@MYVIN/1A23/1/A13"; "for authority with userinfo")]
     #[test_case("up://MY#VIN/55A1/1/1"; "for invalid authority")]
+    #[test_case("up://MY VIN/55A1/1/1"; "for authority containing whitespace")]
+    #[test_case("up://MY\tVIN/55A1/1/1"; "for authority containing tab")]
+    #[test_case("up://MY\nVIN/55A1/1/1"; "for authority containing newline")]
+    #[test_case("up://MYVIN/55 A1/1/1"; "for entity ID containing whitespace")]
+    #[test_case("up://MYVIN/55A1/1/\t1"; "for resource ID containing tab")]
+    #[test_case("ü:/A100/1/2"; "for multibyte character immediately before colon")]
+    #[test_case("upü://MYVIN/A100/1/2"; "for scheme ending with multibyte character")]
+    #[test_case("//MYVINü/A100/1/2"; "for authority containing multibyte character")]
+    #[test_case("//MYVIN/A100/1/2ü"; "for resource ID containing multibyte character")]
+    #[test_case("//MYVIN/A100/1/2:"; "for resource ID containing colon")]
     #[test_case("up://MYVIN/55T1/1/1"; "for non-hex entity ID")]
     #[test_case("up://MYVIN/123456789/1/1"; "for entity ID exceeding max length")]
     #[test_case("up://MYVIN/55A1//1"; "for empty version")]
@@ -928,6 +1603,46 @@ mod tests {
         assert!(parsing_result.is_err());
     }
 
+    #[test]
+    fn test_check_validity_reports_all_violations() {
+        let uuri = UUri {
+            authority_name: "invalid:5671".into(),
+            ue_id: 0x0000_8000,
+            ue_version_major: 0x0101,
+            resource_id: 0x10002,
+            ..Default::default()
+        };
+        let violations = uuri.validation_errors();
+        assert_eq!(violations.len(), 3);
+        assert!(violations
+            .iter()
+            .all(|e| matches!(e, UUriError::ValidationError(_))));
+        assert!(violations[0].to_string().contains("port"));
+        assert!(violations[1].to_string().contains("major version"));
+        assert!(violations[2].to_string().contains("resource ID"));
+
+        let Err(UUriError::ValidationError(msg)) = uuri.check_validity() else {
+            panic!("expected validation error");
+        };
+        let expected_message = violations
+            .iter()
+            .map(|e| match e {
+                UUriError::SerializationError(msg) | UUriError::ValidationError(msg) => {
+                    msg.as_str()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        assert_eq!(msg, expected_message);
+    }
+
+    #[test]
+    fn test_validation_errors_is_empty_for_valid_uri() {
+        let uuri = UUri::try_from_parts("vcu.my_car_vin", 0x0010_8000, 0x01, 0x8001).unwrap();
+        assert!(uuri.validation_errors().is_empty());
+        assert!(uuri.check_validity().is_ok());
+    }
+
     // [utest->req~uri-serialization~1]
     // [utest->dsn~uri-scheme~1]
     // [utest->dsn~uri-host-only~2]
@@ -978,6 +1693,24 @@ mod tests {
             ..Default::default()
         };
         "for remote uri with wildcards")]
+    #[test_case("//192.168.1.100/A100/1/2",
+        UUri {
+            authority_name: "192.168.1.100".to_string(),
+            ue_id: 0x0000_A100,
+            ue_version_major: 0x01,
+            resource_id: 0x0002,
+            ..Default::default()
+        };
+        "for remote uri with IPv4 address")]
+    #[test_case("//[2001:db8::1]/A100/1/2",
+        UUri {
+            authority_name: "[2001:db8::1]".to_string(),
+            ue_id: 0x0000_A100,
+            ue_version_major: 0x01,
+            resource_id: 0x0002,
+            ..Default::default()
+        };
+        "for remote uri with IPv6 address")]
     fn test_from_string_succeeds(uri: &str, expected_uuri: UUri) {
         let parsing_result = UUri::from_str(uri);
         if parsing_result.is_err() {
@@ -988,6 +1721,87 @@ mod tests {
         assert_eq!(expected_uuri, parsed_uuri);
     }
 
+    // [utest->req~uri-serialization~1]
+    #[test_case("//127.0.0.1/A100/1/2"; "for IPv4 address")]
+    #[test_case("//[::1]/A100/1/2"; "for IPv6 address")]
+    #[test_case("//[2001:db8::ff00:42:8329]/A100/1/8001"; "for full IPv6 address")]
+    fn test_ip_address_authority_round_trip(uri: &str) {
+        let uuri = UUri::from_str(uri).expect("should have been able to deserialize URI");
+        assert_eq!(uuri.to_uri(false), uri);
+        assert_eq!(UUri::from_str(&uuri.to_uri(true)).unwrap(), uuri);
+    }
+
+    #[test_case("//VCU.vin/A100/1/2", "//vcu.VIN/A100/1/2"; "for authorities differing in case")]
+    #[test_case("//VCU.%76in/A100/1/2", "//vcu.vin/A100/1/2"; "for authority with percent-encoded unreserved character")]
+    #[test_case("/A100/1/2", "/A100/1/2"; "for local URIs")]
+    fn test_from_str_normalized_succeeds(uri: &str, other_uri: &str) {
+        let normalized_uri = UUri::from_str_normalized(uri).unwrap();
+        assert_eq!(
+            normalized_uri,
+            UUri::from_str_normalized(other_uri).unwrap()
+        );
+        assert_eq!(
+            normalized_uri.authority_name,
+            normalized_uri.authority_name.to_lowercase()
+        );
+    }
+
+    #[test]
+    fn test_from_str_preserves_authority_case() {
+        let uri = UUri::from_str("//VCU.vin/A100/1/2").unwrap();
+        assert_eq!(uri.authority_name, "VCU.vin");
+        assert_ne!(uri, UUri::from_str("//vcu.VIN/A100/1/2").unwrap());
+
+        let uri = UUri::from_str("//VCU%2evin/A100/1/2").unwrap();
+        assert_eq!(uri.authority_name, "VCU%2evin");
+
+        // IP addresses are converted to their canonical form
+        let uri = UUri::from_str("//[2001:DB8:0::1]/A100/1/2").unwrap();
+        assert_eq!(uri.authority_name, "[2001:db8::1]");
+        assert_eq!(uri, UUri::from_str("//[2001:db8::1]/A100/1/2").unwrap());
+    }
+
+    proptest::proptest! {
+        // [utest->req~uri-serialization~1]
+        #[test]
+        fn test_serialization_round_trip(
+            authority in "(\\*|[a-z0-9_-]{1,20}(\\.[a-z0-9_-]{1,20}){0,3})?",
+            entity_id in proptest::num::u32::ANY,
+            entity_version in proptest::num::u8::ANY,
+            resource_id in proptest::num::u16::ANY,
+            include_scheme in proptest::bool::ANY,
+        ) {
+            let uuri = UUri::try_from_parts(&authority, entity_id, entity_version, resource_id)
+                .unwrap();
+            let serialized_uri = uuri.to_uri(include_scheme);
+            let parsed_uri = UUri::from_str(&serialized_uri).unwrap();
+            proptest::prop_assert_eq!(&parsed_uri, &uuri);
+            proptest::prop_assert_eq!(parsed_uri.to_uri(include_scheme), serialized_uri);
+        }
+    }
+
+    // [utest->dsn~uri-scheme~1]
+    #[test_case("up://vcu.vin/A100/1/2"; "for lower case scheme")]
+    #[test_case("UP://vcu.vin/A100/1/2"; "for upper case scheme")]
+    #[test_case("Up://vcu.vin/A100/1/2"; "for capitalized scheme")]
+    #[test_case("uP://vcu.vin/A100/1/2"; "for mixed case scheme")]
+    fn test_from_str_accepts_scheme_regardless_of_case(uri: &str) {
+        let uuri = UUri::from_str(uri).expect("should have been able to deserialize URI");
+        assert_eq!(uuri, UUri::from_str("//vcu.vin/A100/1/2").unwrap());
+    }
+
+    #[test_case("//*/FFFF/FF/FFFF", 0; "for wildcards only")]
+    #[test_case("//VIN/FFFF/FF/FFFF", 1; "for specific authority only")]
+    #[test_case("//*/A100/FF/FFFF", 1; "for specific entity only")]
+    #[test_case("//VIN/A100/FF/FFFF", 2; "for specific authority and entity")]
+    #[test_case("/A100/1/FFFF", 3; "for local URI with any resource")]
+    #[test_case("//*/A100/1/8001", 3; "for any authority")]
+    #[test_case("//VIN/A100/1/8001", 4; "for URI without wildcards")]
+    fn test_specificity(uri: &str, expected_specificity: u8) {
+        let uuri = UUri::from_str(uri).unwrap();
+        assert_eq!(uuri.specificity(), expected_specificity);
+    }
+
     #[test_case("//*/A100/1/1"; "for any authority")]
     #[test_case("//VIN/FFFF/1/1"; "for any entity")]
     #[test_case("//VIN/A100/FF/1"; "for any version")]
@@ -997,6 +1811,291 @@ mod tests {
         assert!(uuri.verify_no_wildcards().is_err());
     }
 
+    #[test]
+    fn test_ordering_sorts_by_authority_entity_version_and_resource() {
+        let mut uris: Vec<UUri> = [
+            "//vehicle-b/A100/1/1",
+            "//vehicle-a/A100/2/1",
+            "/A100/1/8001",
+            "//vehicle-a/A100/1/2",
+            "//vehicle-a/A100/1/1",
+            "//vehicle-a/10A100/1/1",
+            "/A100/1/1",
+        ]
+        .iter()
+        .map(|uri| UUri::from_str(uri).unwrap())
+        .collect();
+        uris.sort();
+
+        let sorted: Vec<String> = uris.iter().map(String::from).collect();
+        assert_eq!(
+            sorted,
+            vec![
+                "/A100/1/1",
+                "/A100/1/8001",
+                "//vehicle-a/A100/1/1",
+                "//vehicle-a/A100/1/2",
+                "//vehicle-a/A100/2/1",
+                "//vehicle-a/10A100/1/1",
+                "//vehicle-b/A100/1/1",
+            ]
+        );
+    }
+
+    #[test_case("/A100/1/8001", 4, "/A100/1/8001"; "for local URI")]
+    #[test_case("//vcu/A100/1/8001", 4, "//vcu/A100/1/8001"; "for short authority")]
+    #[test_case("//vcu1/A100/1/8001", 4, "//vcu1/A100/1/8001"; "for authority of max length")]
+    #[test_case("//vcu.my-car/A100/1/8001", 4, "//vcu.…/A100/1/8001"; "for long authority")]
+    #[test_case("//vcu/A100/1/8001", 0, "//…/A100/1/8001"; "for zero max length")]
+    fn test_short_display(uri: &str, max_authority_length: usize, expected: &str) {
+        let uuri = UUri::from_str(uri).unwrap();
+        assert_eq!(uuri.short_display(max_authority_length), expected);
+    }
+
+    #[test]
+    fn test_parse_many_collects_successes_and_errors() {
+        let lines = "//vcu/A100/1/8001\n\n/A100/1/1\ncustom://vcu/A100/1/1\n//vcu/A100/1";
+        let (uris, errors) = UUri::parse_many(lines.lines());
+
+        assert_eq!(
+            uris,
+            vec![
+                UUri::try_from_parts("vcu", 0xA100, 0x01, 0x8001).unwrap(),
+                UUri::try_from_parts("", 0xA100, 0x01, 0x0001).unwrap(),
+            ]
+        );
+        let failed_lines: Vec<usize> = errors.iter().map(|(index, _)| *index).collect();
+        assert_eq!(failed_lines, vec![1, 3, 4]);
+        assert!(errors
+            .iter()
+            .all(|(_, e)| matches!(e, UUriError::SerializationError(_))));
+    }
+
+    #[test_case(UUri::default(), UUriKind::Empty; "for empty URI")]
+    #[test_case(UUri::try_from("/8000/1/0").unwrap(), UUriKind::RpcResponse; "for local rpc service response")]
+    #[test_case(UUri::try_from("//VCU.MY_CAR_VIN/108000/1/0").unwrap(), UUriKind::RpcResponse; "for remote rpc service response")]
+    #[test_case(UUri::try_from("/108000/1/2").unwrap(), UUriKind::RpcMethod; "for local service method")]
+    #[test_case(UUri::try_from("//VCU.MY_CAR_VIN/108000/1/7FFF").unwrap(), UUriKind::RpcMethod; "for max method ID")]
+    #[test_case(UUri::try_from("//VCU.MY_CAR_VIN/108000/1/8000").unwrap(), UUriKind::Topic; "for min topic ID")]
+    #[test_case(UUri::try_from("//*/FFFF/FF/FFFF").unwrap(), UUriKind::Topic; "for remote uri with wildcards")]
+    fn test_kind(uuri: UUri, expected_kind: UUriKind) {
+        assert_eq!(uuri.kind(), expected_kind);
+    }
+
+    #[test_case("//vcu/A100/1/8001", "//vcu/A100/1/8001", "//vcu/A100/1/8001"; "for identical URIs")]
+    #[test_case("//vcu/A100/1/8001", "//vcu/A100/1/8002", "//vcu/A100/1/FFFF"; "for URIs differing in resource")]
+    #[test_case("//vcu/A100/1/8001", "//vcu/A100/2/8001", "//vcu/A100/FF/FFFF"; "for URIs differing in version")]
+    #[test_case("//vcu/A100/1/8001", "//vcu/B200/1/8001", "//vcu/FFFF/FF/FFFF"; "for URIs differing in entity")]
+    #[test_case("//vcu/A100/1/8001", "//other/B200/2/1", "//*/FFFF/FF/FFFF"; "for disjoint URIs")]
+    #[test_case("/A100/1/8001", "//vcu/A100/1/8001", "//*/FFFF/FF/FFFF"; "for local and remote URI")]
+    fn test_common_prefix(uri: &str, other: &str, expected_prefix: &str) {
+        let uri = UUri::from_str(uri).unwrap();
+        let other = UUri::from_str(other).unwrap();
+        let prefix = uri.common_prefix(&other);
+        assert_eq!(prefix, UUri::from_str(expected_prefix).unwrap());
+        assert_eq!(other.common_prefix(&uri), prefix);
+        assert!(prefix.matches(&uri));
+        assert!(prefix.matches(&other));
+    }
+
+    #[test_case("//vcu/A100/1234567890123456789012345678901234567890/1", "too large"; "for 40 digit version")]
+    #[test_case("//vcu/A100/100/1", "too large"; "for version exceeding 8 bits")]
+    #[test_case("//vcu/A100/xy/1", "invalid digit"; "for non-hex version")]
+    #[test_case("//vcu/123456789/1/1", "too large"; "for entity ID exceeding 32 bits")]
+    #[test_case("//vcu/A100/1/10000", "too large"; "for resource ID exceeding 16 bits")]
+    #[test_case("//vcu/A100/1/xyz", "invalid digit"; "for non-hex resource ID")]
+    fn test_from_str_distinguishes_overflow_from_invalid_digits(uri: &str, expected_cause: &str) {
+        assert!(UUri::from_str(uri).is_err_and(|e| e.to_string().contains(expected_cause)));
+    }
+
+    #[test_case("//vcu/-A100/1/1", "Entity ID"; "for negative entity ID")]
+    #[test_case("//vcu/+A100/1/1", "Entity ID"; "for entity ID with plus sign")]
+    #[test_case("//vcu/A100/-1/1", "Entity version"; "for negative version")]
+    #[test_case("//vcu/A100/+1/1", "Entity version"; "for version with plus sign")]
+    #[test_case("//vcu/A100/1/-1", "Resource ID"; "for negative resource ID")]
+    #[test_case("//vcu/A100/1/+1", "Resource ID"; "for resource ID with plus sign")]
+    fn test_from_str_fails_for_signed_segment(uri: &str, segment_name: &str) {
+        let expected_message = format!(
+            "{} must be an unsigned hex number without sign",
+            segment_name
+        );
+        assert!(UUri::from_str(uri).is_err_and(|e| e.to_string().contains(&expected_message)));
+    }
+
+    // [utest->dsn~uri-host-only~2]
+    #[test_case("//vcu.vin:sometransport/A100/1/2"; "for URI without scheme")]
+    #[test_case("up://vcu.vin:sometransport/A100/1/2"; "for URI with scheme")]
+    fn test_from_str_fails_for_authority_with_non_numeric_port(uri: &str) {
+        // uriparse already rejects the authority, i.e. before the port is checked explicitly
+        assert!(UUri::from_str(uri).is_err_and(|e| matches!(e,
+            UUriError::SerializationError(msg) if msg.contains("invalid port character"))));
+    }
+
+    // [utest->dsn~uri-scheme~1]
+    #[test_case("up:up:/A100/1/2"; "for duplicate up scheme")]
+    #[test_case("up:custom:/A100/1/2"; "for nested custom scheme")]
+    #[test_case("up:UP://vcu/A100/1/2"; "for duplicate scheme with authority")]
+    #[test_case("up:up:A100/1/2"; "for duplicate scheme with relative path")]
+    fn test_from_str_fails_for_multiple_schemes(uri: &str) {
+        assert!(UUri::from_str(uri).is_err_and(|e| e
+            .to_string()
+            .contains("must not contain more than one scheme")));
+    }
+
+    #[test_case("//vcu/A1:00/1/2"; "for URI with authority")]
+    #[test_case("up://vcu/A1:00/1/2"; "for URI with scheme and authority")]
+    #[test_case("up:/A1:00/1/2"; "for local URI with scheme")]
+    fn test_from_str_fails_for_colon_in_entity_id(uri: &str) {
+        assert!(UUri::from_str(uri).is_err_and(|e| {
+            let msg = e.to_string();
+            msg.contains("Cannot parse entity ID") && !msg.contains("more than one scheme")
+        }));
+    }
+
+    #[test]
+    fn test_hash_is_consistent_with_equality() {
+        let uri = UUri::from_str("up://vehicle/A100/1/8001").unwrap();
+        let same_uri = UUri::try_from_parts("vehicle", 0xA100, 0x01, 0x8001).unwrap();
+        let other_uri = UUri::from_str("//vehicle/A100/1/8002").unwrap();
+        assert_eq!(uri, same_uri);
+
+        let mut subscriptions = std::collections::HashMap::new();
+        subscriptions.insert(uri, "first");
+        assert_eq!(subscriptions.get(&same_uri), Some(&"first"));
+        assert!(!subscriptions.contains_key(&other_uri));
+
+        subscriptions.insert(same_uri, "second");
+        assert_eq!(subscriptions.len(), 1);
+        assert!(!subscriptions.contains_key(&UUri::from_str("/A100/1/8001").unwrap()));
+    }
+
+    #[cfg(feature = "ahash")]
+    #[test]
+    fn test_uuri_map_lookup() {
+        let mut map = UUriMap::default();
+        map.insert(UUri::from_str("//vehicle/A100/1/8001").unwrap(), 1);
+        map.insert(UUri::from_str("/A100/1/8001").unwrap(), 2);
+
+        let key = UUri::try_from_parts("vehicle", 0xA100, 0x01, 0x8001).unwrap();
+        assert_eq!(map.get(&key), Some(&1));
+        assert_eq!(map.len(), 2);
+        assert!(!map.contains_key(&UUri::from_str("//vehicle/A100/1/8002").unwrap()));
+    }
+
+    #[test]
+    fn test_ordering_is_consistent_with_equality() {
+        let uri = UUri::from_str("//vehicle/A100/1/1").unwrap();
+        let same_uri = UUri::try_from_parts("vehicle", 0xA100, 0x01, 0x0001).unwrap();
+        assert_eq!(uri.cmp(&same_uri), Ordering::Equal);
+
+        let mut routes = std::collections::BTreeMap::new();
+        routes.insert(uri.clone(), "first");
+        routes.insert(same_uri, "second");
+        assert_eq!(routes.len(), 1);
+
+        // a URI that has been decoded from protobuf may carry unknown fields
+        let mut uri_with_unknown_fields = uri.clone();
+        uri_with_unknown_fields
+            .special_fields
+            .mut_unknown_fields()
+            .add_varint(100, 1);
+        assert_ne!(uri, uri_with_unknown_fields);
+        assert_ne!(uri.cmp(&uri_with_unknown_fields), Ordering::Equal);
+        assert_eq!(
+            uri.cmp(&uri_with_unknown_fields),
+            uri_with_unknown_fields.cmp(&uri).reverse()
+        );
+
+        let mut other_uri_with_unknown_fields = uri.clone();
+        other_uri_with_unknown_fields
+            .special_fields
+            .mut_unknown_fields()
+            .add_varint(100, 2);
+        assert_ne!(uri_with_unknown_fields, other_uri_with_unknown_fields);
+        assert_ne!(
+            uri_with_unknown_fields.cmp(&other_uri_with_unknown_fields),
+            Ordering::Equal
+        );
+
+        // clearing the unknown fields leaves an initialized but empty set behind, which
+        // is not considered equal to the unset unknown fields of the original URI
+        let mut uri_with_cleared_unknown_fields = uri_with_unknown_fields.clone();
+        uri_with_cleared_unknown_fields
+            .special_fields
+            .mut_unknown_fields()
+            .clear();
+        assert_ne!(uri, uri_with_cleared_unknown_fields);
+        assert_eq!(uri.cmp(&uri_with_cleared_unknown_fields), Ordering::Less);
+        assert_eq!(uri_with_cleared_unknown_fields.cmp(&uri), Ordering::Greater);
+
+        routes.insert(uri_with_unknown_fields, "third");
+        routes.insert(other_uri_with_unknown_fields, "fourth");
+        routes.insert(uri_with_cleared_unknown_fields, "fifth");
+        assert_eq!(routes.len(), 4);
+    }
+
+    #[cfg(feature = "http")]
+    #[test_case("up://vcu.vin/A100/1/2"; "for remote URI")]
+    #[test_case("/A100/1/2"; "for local URI")]
+    #[test_case("up://[::1]/A100/1/2"; "for remote URI with IPv6 address")]
+    #[test_case("up://*/FFFF/FF/FFFF"; "for URI with wildcards")]
+    fn test_http_uri_round_trip(uri: &str) {
+        let http_uri: http::Uri = uri.parse().unwrap();
+        let uuri = UUri::try_from(http_uri.clone()).unwrap();
+        assert_eq!(http::Uri::try_from(&uuri).unwrap(), http_uri);
+    }
+
+    #[cfg(feature = "http")]
+    #[test_case("http://vcu.vin/A100/1/2"; "for non-up scheme")]
+    #[test_case("up://vcu.vin:8080/A100/1/2"; "for URI with port")]
+    #[test_case("up://vcu.vin/A100/1/2?foo=bar"; "for URI with query")]
+    #[test_case("up://vcu.vin/body.access/1/door"; "for URI with entity name")]
+    fn test_try_from_http_uri_fails(uri: &str) {
+        let http_uri: http::Uri = uri.parse().unwrap();
+        assert!(UUri::try_from(http_uri).is_err());
+    }
+
+    #[cfg(feature = "url")]
+    #[test_case("up://vcu.vin/A100/1/2"; "for remote URI")]
+    #[test_case("up:/A100/1/2"; "for local URI")]
+    #[test_case("up://[::1]/A100/1/2"; "for remote URI with IPv6 address")]
+    #[test_case("up://*/FFFF/FF/FFFF"; "for URI with wildcards")]
+    fn test_url_round_trip(uri: &str) {
+        let url = url::Url::parse(uri).unwrap();
+        let uuri = UUri::try_from(url.clone()).unwrap();
+        assert_eq!(url::Url::try_from(&uuri).unwrap(), url);
+    }
+
+    #[cfg(feature = "url")]
+    #[test_case("http://vcu.vin/A100/1/2"; "for non-up scheme")]
+    #[test_case("up://vcu.vin:8080/A100/1/2"; "for URL with port")]
+    #[test_case("up://vcu.vin/A100/1/2#foo"; "for URL with fragment")]
+    #[test_case("up://vcu.vin/body.access/1/door"; "for URL with entity name")]
+    fn test_try_from_url_fails(uri: &str) {
+        let url = url::Url::parse(uri).unwrap();
+        assert!(UUri::try_from(url).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test_case("//my-vehicle/A100/1/8001"; "for remote URI")]
+    #[test_case("/A100/1/8001"; "for local URI")]
+    #[test_case("//*/FFFF/FF/FFFF"; "for URI with wildcards")]
+    fn test_serde_round_trip(uri: &str) {
+        let uuri = UUri::from_str(uri).unwrap();
+        let json = serde_json::to_string(&uuri).unwrap();
+        assert_eq!(json, format!("\"{}\"", uri));
+        let deserialized_uri: UUri = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized_uri, uuri);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test_case("\"custom://my-vehicle/A100/1/8001\""; "for unsupported scheme")]
+    #[test_case("\"//my-vehicle/A100/1\""; "for missing resource ID")]
+    #[test_case("42"; "for non-string value")]
+    fn test_serde_deserialization_fails(json: &str) {
+        assert!(serde_json::from_str::<UUri>(json).is_err());
+    }
+
     // [utest->req~uri-data-model-proto~1]
     #[test]
     fn test_protobuf_serialization() {
@@ -1012,6 +2111,29 @@ mod tests {
         assert_eq!(uri, deserialized_uri);
     }
 
+    #[test]
+    fn test_from_str_fails_for_input_exceeding_max_length() {
+        let uri = "/".repeat(1024 * 1024);
+        assert!(
+            UUri::from_str(&uri).is_err_and(|e| e.to_string().contains("exceeds maximum length"))
+        );
+
+        let uri = format!("//{}/A100/1/6501", "a".repeat(DEFAULT_MAX_URI_LENGTH));
+        assert!(
+            UUri::from_str(&uri).is_err_and(|e| e.to_string().contains("exceeds maximum length"))
+        );
+    }
+
+    #[test_case("/A100/1/6501", 12, true; "for input of maximum length")]
+    #[test_case("/A100/1/6501", 11, false; "for input exceeding maximum length")]
+    #[test_case("//my-vehicle/A100/1/6501", 4096, true; "for input below maximum length")]
+    fn test_from_str_with_limit(uri: &str, max_length: usize, should_succeed: bool) {
+        assert_eq!(
+            UUri::from_str_with_limit(uri, max_length).is_ok(),
+            should_succeed
+        );
+    }
+
     // [utest->dsn~uri-authority-name-length~1]
     #[test]
     fn test_from_str_fails_for_authority_exceeding_max_length() {
@@ -1043,6 +2165,8 @@ mod tests {
     #[test_case("MYVIN:1000"; "with port")]
     #[test_case("user:pwd@MYVIN"; "with userinfo")]
     #[test_case("MY%VIN"; "with reserved character")]
+    #[test_case("MY VIN"; "with whitespace")]
+    #[test_case("MY\u{7}VIN"; "with control character")]
     fn test_try_from_parts_fails_for_invalid_authority(authority: &str) {
         assert!(UUri::try_from_parts(authority, 0xa100, 0x01, 0x6501).is_err());
     }
@@ -1078,4 +2202,36 @@ mod tests {
             UUri::try_from(candidate).expect("should have been able to create candidate UUri");
         assert!(!pattern_uri.matches(&candidate_uri));
     }
+
+    #[test_case("//my-vehicle/A100/1/7FFF", "//my-vehicle/A100/1/7FFF"; "for identical URIs")]
+    #[test_case("/A100/1/7FFF", "/A100/1/7FFF"; "for local URIs")]
+    #[test_case("//my-vehicle/A100/1/7FFF", "//my-vehicle/3A100/1/7FFF"; "for method without entity instance")]
+    fn test_verify_rpc_response_matches_request_succeeds(
+        request_method: &str,
+        response_source: &str,
+    ) {
+        let request_method = UUri::try_from(request_method).unwrap();
+        let response_source = UUri::try_from(response_source).unwrap();
+        assert!(
+            UUri::verify_rpc_response_matches_request(&request_method, &response_source).is_ok()
+        );
+    }
+
+    #[test_case("//my-vehicle/A100/1/7FFF", "//other-vehicle/A100/1/7FFF"; "for different authority")]
+    #[test_case("//my-vehicle/A100/1/7FFF", "//my-vehicle/B200/1/7FFF"; "for different entity")]
+    #[test_case("//my-vehicle/2A100/1/7FFF", "//my-vehicle/3A100/1/7FFF"; "for different entity instance")]
+    #[test_case("//my-vehicle/A100/1/7FFF", "//my-vehicle/A100/2/7FFF"; "for different entity version")]
+    #[test_case("//my-vehicle/A100/1/7FFF", "//my-vehicle/A100/1/7FFE"; "for different method")]
+    #[test_case("//my-vehicle/A100/1/8001", "//my-vehicle/A100/1/8001"; "for request to topic")]
+    #[test_case("//*/A100/1/7FFF", "//my-vehicle/A100/1/7FFF"; "for request to method with wildcard authority")]
+    #[test_case("//my-vehicle/FFFF/1/7FFF", "//my-vehicle/A100/1/7FFF"; "for request to method with wildcard entity ID")]
+    #[test_case("//my-vehicle/A100/1/7FFF", "//my-vehicle/A100/1/0"; "for response from RPC response address")]
+    fn test_verify_rpc_response_matches_request_fails(request_method: &str, response_source: &str) {
+        let request_method = UUri::try_from(request_method).unwrap();
+        let response_source = UUri::try_from(response_source).unwrap();
+        assert!(
+            UUri::verify_rpc_response_matches_request(&request_method, &response_source)
+                .is_err_and(|e| matches!(e, UUriError::ValidationError(_)))
+        );
+    }
 }
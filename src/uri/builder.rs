@@ -0,0 +1,175 @@
+/********************************************************************************
+ * Copyright (c) 2024 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+use crate::{UUri, UUriError};
+
+/// A builder for creating [`UUri`]s.
+///
+/// The builder starts out with an empty (local) authority and all IDs set to `0`.
+#[derive(Debug, Default)]
+pub struct UUriBuilder {
+    authority_name: String,
+    entity_id: u32,
+    entity_version: u8,
+    resource_id: u16,
+}
+
+impl UUriBuilder {
+    /// Creates a new builder for a local URI with all IDs set to `0`.
+    pub fn new() -> UUriBuilder {
+        UUriBuilder::default()
+    }
+
+    /// Sets the name of the authority that the URI refers to.
+    ///
+    /// # Arguments
+    ///
+    /// * `authority_name` - The authority's name. An empty name results in a local URI.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUriBuilder;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let uri = UUriBuilder::new()
+    ///               .with_authority_name("my-vehicle")
+    ///               .build()?;
+    /// assert_eq!(uri.authority_name, "my-vehicle");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_authority_name<T: Into<String>>(&mut self, authority_name: T) -> &mut UUriBuilder {
+        self.authority_name = authority_name.into();
+        self
+    }
+
+    /// Sets the uEntity that the URI refers to.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity_id` - The entity's identifier, including the instance ID in the most significant 16 bits.
+    /// * `entity_version` - The entity's major version.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUriBuilder;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let uri = UUriBuilder::new()
+    ///               .with_entity(0x0001_A100, 0x02)
+    ///               .build()?;
+    /// assert_eq!(uri.ue_id, 0x0001_A100);
+    /// assert_eq!(uri.ue_version_major, 0x02);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_entity(&mut self, entity_id: u32, entity_version: u8) -> &mut UUriBuilder {
+        self.entity_id = entity_id;
+        self.entity_version = entity_version;
+        self
+    }
+
+    /// Sets the resource that the URI refers to.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource's identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUriBuilder;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let uri = UUriBuilder::new()
+    ///               .with_resource_id(0x8001)
+    ///               .build()?;
+    /// assert_eq!(uri.resource_id, 0x8001);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_resource_id(&mut self, resource_id: u16) -> &mut UUriBuilder {
+        self.resource_id = resource_id;
+        self
+    }
+
+    /// Creates the URI based on the builder's state.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UUriError::ValidationError`] if the authority name does not comply with
+    /// the UUri specification.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{UUri, UUriBuilder};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let uri = UUriBuilder::new()
+    ///               .with_authority_name("my-vehicle")
+    ///               .with_entity(0xA100, 0x01)
+    ///               .with_resource_id(0x8001)
+    ///               .build()?;
+    /// assert_eq!(uri, UUri::try_from("//my-vehicle/A100/1/8001")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build(&self) -> Result<UUri, UUriError> {
+        UUri::try_from_parts(
+            &self.authority_name,
+            self.entity_id,
+            self.entity_version,
+            self.resource_id,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("", 0x0000_8000, 0x01, 0x0002; "for local URI")]
+    #[test_case("VCU.MY_CAR_VIN", 0x0010_8000, 0x01, 0x0002; "for remote URI")]
+    #[test_case("*", 0x0000_FFFF, 0xFF, 0xFFFF; "for URI with wildcards")]
+    fn test_build_succeeds(authority: &str, entity_id: u32, version: u8, resource_id: u16) {
+        let uri = UUriBuilder::new()
+            .with_authority_name(authority)
+            .with_entity(entity_id, version)
+            .with_resource_id(resource_id)
+            .build()
+            .expect("should have been able to build URI");
+        let expected = UUri {
+            authority_name: authority.to_string(),
+            ue_id: entity_id,
+            ue_version_major: version as u32,
+            resource_id: resource_id as u32,
+            ..Default::default()
+        };
+        assert_eq!(uri, expected);
+    }
+
+    #[test_case("MYVIN:1000"; "with port")]
+    #[test_case("user:pwd@MYVIN"; "with userinfo")]
+    #[test_case("MY%VIN"; "with reserved character")]
+    fn test_build_fails_for_invalid_authority(authority: &str) {
+        let result = UUriBuilder::new()
+            .with_authority_name(authority)
+            .with_entity(0xA100, 0x01)
+            .build();
+        assert!(result.is_err_and(|e| matches!(e, UUriError::ValidationError(_))));
+    }
+}
@@ -13,6 +13,8 @@
 
 use std::error::Error;
 
+use protobuf::Enum;
+
 pub use crate::up_core_api::ucode::UCode;
 pub use crate::up_core_api::ustatus::UStatus;
 
@@ -152,13 +154,65 @@ impl UStatus {
     }
 }
 
+impl From<UCode> for UStatus {
+    /// Creates a status for a code.
+    ///
+    /// The created status does not contain an error message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{UCode, UStatus};
+    ///
+    /// let status = UStatus::from(UCode::NOT_FOUND);
+    /// assert!(status.is_failed());
+    /// assert_eq!(status.get_code(), UCode::NOT_FOUND);
+    /// assert!(status.message.is_none());
+    /// ```
+    fn from(code: UCode) -> Self {
+        UStatus {
+            code: code.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<i32> for UCode {
+    type Error = UStatus;
+
+    /// Gets the code for a numeric value.
+    ///
+    /// # Errors
+    ///
+    /// Returns a status with code [`UCode::INVALID_ARGUMENT`] if the value does not
+    /// correspond to any of the defined codes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UCode;
+    ///
+    /// assert_eq!(UCode::try_from(5), Ok(UCode::NOT_FOUND));
+    /// assert!(UCode::try_from(42).is_err());
+    /// ```
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        UCode::from_i32(value).ok_or_else(|| {
+            UStatus::fail_with_code(
+                UCode::INVALID_ARGUMENT,
+                format!("unknown status code [{}]", value),
+            )
+        })
+    }
+}
+
 impl Error for UStatus {}
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use protobuf::{Enum, EnumOrUnknown};
+    use protobuf::EnumOrUnknown;
+    use test_case::test_case;
 
     #[test]
     fn test_is_failed() {
@@ -189,4 +243,45 @@ mod tests {
             assert_eq!(ustatus.is_success(), *code == UCode::OK);
         });
     }
+
+    #[test]
+    fn test_ok() {
+        let status = UStatus::ok();
+        assert!(status.is_success());
+        assert_eq!(status.get_code(), UCode::OK);
+        assert!(status.message.is_none());
+    }
+
+    #[test]
+    fn test_fail_with_code() {
+        let status = UStatus::fail_with_code(UCode::NOT_FOUND, "no such resource");
+        assert!(status.is_failed());
+        assert_eq!(status.get_code(), UCode::NOT_FOUND);
+        assert_eq!(status.get_message(), "no such resource");
+    }
+
+    #[test]
+    fn test_from_ucode() {
+        UCode::VALUES.iter().for_each(|code| {
+            let ustatus = UStatus::from(*code);
+            assert_eq!(ustatus.get_code(), *code);
+            assert_eq!(ustatus.is_success(), *code == UCode::OK);
+            assert!(ustatus.message.is_none());
+        });
+    }
+
+    #[test]
+    fn test_ucode_try_from_i32_succeeds() {
+        UCode::VALUES.iter().for_each(|code| {
+            assert_eq!(UCode::try_from(code.value()), Ok(*code));
+        });
+    }
+
+    #[test_case(-1; "for negative value")]
+    #[test_case(17; "for value right above range")]
+    #[test_case(i32::MAX; "for max value")]
+    fn test_ucode_try_from_i32_fails(value: i32) {
+        let result = UCode::try_from(value);
+        assert!(result.is_err_and(|status| status.get_code() == UCode::INVALID_ARGUMENT));
+    }
 }